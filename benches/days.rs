@@ -0,0 +1,135 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Benchmarks every day against its example and, if present, its real input: `cargo bench`.
+//!
+//! Free arguments select days, e.g. `cargo bench -- 16 22`. `--year`, `--warmup <n>` and
+//! `--bench-time <ms>` work like for `cargo solve`.
+
+use std::process;
+use std::time::Duration;
+
+use advent_of_code::bench::{bench_part, BenchConfig, Stats};
+use advent_of_code::solution::{
+    catching, parse_catching, solve_catching, Outcome, PartResult, Solution,
+};
+use advent_of_code::years::{self, YEARS};
+
+struct Args {
+    year: Option<u16>,
+    days: Vec<u8>,
+    config: BenchConfig,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    // passed by `cargo bench` itself.
+    args.contains("--bench");
+
+    let default = BenchConfig {
+        budget: Duration::from_millis(200),
+        ..BenchConfig::default()
+    };
+    let config = BenchConfig {
+        warmup: args
+            .opt_value_from_str("--warmup")?
+            .unwrap_or(default.warmup),
+        budget: args
+            .opt_value_from_str("--bench-time")?
+            .map_or(default.budget, Duration::from_millis),
+    };
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+
+    let mut days = vec![];
+    for arg in args.finish() {
+        let arg = arg.to_string_lossy();
+        days.push(
+            arg.parse()
+                .map_err(|_| pico_args::Error::ArgumentParsingFailed {
+                    cause: format!("invalid day \"{}\"", arg),
+                })?,
+        );
+    }
+    Ok(Args { year, days, config })
+}
+
+fn render_stats(stats: &Stats) -> String {
+    format!(
+        "{:>10.2?} median, {:.2?} min, ± {:.2?} ({} runs)",
+        stats.median, stats.min, stats.stddev, stats.iterations
+    )
+}
+
+fn render((result, stats): (PartResult, Option<Stats>)) -> String {
+    match (&result.outcome, stats) {
+        (_, Some(stats)) => render_stats(&stats),
+        (Outcome::Unsolved, _) => "not solved".to_string(),
+        (Outcome::Error(e), _) => format!("error: {}", e),
+        (outcome, None) => format!("{:?}", outcome),
+    }
+}
+
+fn bench_day(solution: &dyn Solution, config: &BenchConfig) {
+    let (year, day) = (solution.year(), solution.day());
+
+    for (label, folder) in [("example", "examples"), ("input", "inputs")] {
+        let prefix = format!("{} day {:02} {:<7}", year, day, label);
+        let input = match advent_of_code::try_read_file(year, folder, day) {
+            Ok(input) if !input.is_empty() => input,
+            _ => {
+                println!("{} skipped, no {} file", prefix, label);
+                continue;
+            }
+        };
+
+        match parse_catching(solution, &input) {
+            Some(parsed) => {
+                let parse = bench_part(
+                    || parse_catching(solution, &input).map_or(parsed.result.clone(), |p| p.result),
+                    config,
+                );
+                println!("{} parse  {}", prefix, render(parse));
+                for part in [1, 2] {
+                    let result = bench_part(|| catching(|| parsed.solve(part)), config);
+                    println!("{} part {} {}", prefix, part, render(result));
+                }
+            }
+            None => {
+                for part in [1, 2] {
+                    let result = bench_part(|| solve_catching(solution, part, &input), config);
+                    println!("{} part {} {}", prefix, part, render(result));
+                }
+            }
+        }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let years: Vec<_> = match args.year {
+        Some(year) => match years::find(year) {
+            Some(year) => vec![year],
+            None => {
+                eprintln!("No solutions for {} yet", year);
+                process::exit(1);
+            }
+        },
+        None => YEARS.iter().collect(),
+    };
+
+    for year in years {
+        for solution in year.solutions {
+            if args.days.is_empty() || args.days.contains(&solution.day()) {
+                bench_day(*solution, &args.config);
+            }
+        }
+    }
+}
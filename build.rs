@@ -0,0 +1,61 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+
+/// Generates a test for every example variant `yYYYY/examples/NN-<name>.txt`, see
+/// `src/examples.rs`.
+fn main() {
+    println!("cargo:rerun-if-env-changed=AOC_DATA_DIR");
+    let data_dir = env::var_os("AOC_DATA_DIR")
+        .filter(|dir| !dir.is_empty())
+        .map_or_else(|| PathBuf::from("src"), PathBuf::from);
+    println!("cargo:rerun-if-changed={}", data_dir.display());
+
+    let years: Vec<u16> = fs::read_dir(&data_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let dir_name = entry.ok()?.file_name().into_string().ok()?;
+            dir_name.strip_prefix('y')?.parse().ok()
+        })
+        .collect();
+
+    let mut variants: Vec<(u16, u8, String)> = vec![];
+    for year in years {
+        let examples_dir = data_dir.join(format!("y{}", year)).join("examples");
+        println!("cargo:rerun-if-changed={}", examples_dir.display());
+        variants.extend(
+            fs::read_dir(&examples_dir)
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| {
+                    let file_name = entry.ok()?.file_name().into_string().ok()?;
+                    let (day, name) = file_name.strip_suffix(".txt")?.split_once('-')?;
+                    Some((year, day.parse().ok()?, name.to_string()))
+                }),
+        );
+    }
+    variants.sort();
+
+    let mut tests = String::new();
+    for (year, day, name) in variants {
+        let ident: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        writeln!(
+            tests,
+            "#[test]\nfn y{}_day{:02}_{}() {{\n    crate::examples::check({}, {}, {:?}).unwrap_or_else(|e| panic!(\"{{}}\", e));\n}}\n",
+            year, day, ident, year, day, name
+        )
+        .unwrap();
+    }
+
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    fs::write(out_dir.join("example_tests.rs"), tests).unwrap();
+}
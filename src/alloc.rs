@@ -0,0 +1,128 @@
+//! Heap accounting per part, enabled with the `alloc-stats` cargo feature.
+//!
+//! The feature installs [`CountingAllocator`] as the global allocator. It counts per thread, so
+//! days running in parallel (see `--jobs`) do not skew each other's numbers.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Heap usage of a single run of a part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AllocStats {
+    /// The most memory the part held at once, on top of what was allocated before it started.
+    pub peak_bytes: usize,
+    /// Number of allocations, including reallocations.
+    pub allocations: usize,
+}
+
+thread_local! {
+    /// Bytes currently allocated by this thread. Signed, as a thread may free memory that
+    /// another thread allocated.
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Wraps the system allocator and counts allocations of the current thread.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn track(grown_by: isize, is_allocation: bool) {
+        // `try_with` fails while a thread's locals are torn down, in which case nothing is counted.
+        let _ = CURRENT.try_with(|current| {
+            let now = current.get() + grown_by;
+            current.set(now);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+        });
+        if is_allocation {
+            let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::track(layout.size() as isize, true);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::track(layout.size() as isize, true);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::track(-(layout.size() as isize), false);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::track(new_size as isize - layout.size() as isize, true);
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `func` and measures its heap usage on the current thread.
+///
+/// Returns `None` for the statistics unless the `alloc-stats` feature is enabled.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (func(), None);
+    }
+
+    let baseline = CURRENT.with(Cell::get);
+    let allocations = ALLOCATIONS.with(Cell::get);
+    PEAK.with(|peak| peak.set(baseline));
+
+    let result = func();
+
+    let stats = AllocStats {
+        peak_bytes: (PEAK.with(Cell::get) - baseline).max(0) as usize,
+        allocations: ALLOCATIONS.with(Cell::get) - allocations,
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(not(feature = "alloc-stats"))]
+    fn test_measure_disabled() {
+        assert_eq!(measure(|| vec![0_u8; 1000].len()), (1000, None));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc-stats")]
+    fn test_measure() {
+        let (len, stats) = measure(|| {
+            let first = vec![0_u8; 1000];
+            drop(first);
+            let second = vec![0_u8; 600];
+            second.len()
+        });
+
+        assert_eq!(len, 600);
+        assert_eq!(
+            stats,
+            Some(AllocStats {
+                peak_bytes: 1000,
+                allocations: 2
+            })
+        );
+    }
+}
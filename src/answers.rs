@@ -0,0 +1,291 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::solution::Outcome;
+
+/// A known answer for one part, as written in `src/yYYYY/answers/NN.toml` (see [`crate::data_dir`]):
+///
+/// ```toml
+/// part_one = 24000
+/// part_two = "CMZ"
+/// wrong_one = [23000, 25000]
+/// ```
+///
+/// `wrong_one` and `wrong_two` list answers that were submitted and rejected, see `cargo submit`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Answer {
+    /// Types `answer`, as printed by a solver.
+    pub fn from_output(answer: &str) -> Self {
+        match answer.parse() {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::Text(answer.to_string()),
+        }
+    }
+
+    fn parse(key: &str, value: toml::Value) -> Result<Self, String> {
+        match value {
+            toml::Value::Integer(value) => Ok(Answer::Integer(value)),
+            toml::Value::String(value) => Ok(Answer::Text(value)),
+            value => Err(format!(
+                "`{}` must be an integer or a string, found {}",
+                key,
+                value.type_str()
+            )),
+        }
+    }
+
+    fn to_toml(&self) -> toml::Value {
+        match self {
+            Answer::Integer(value) => toml::Value::Integer(*value),
+            Answer::Text(value) => toml::Value::String(value.clone()),
+        }
+    }
+
+    /// Whether `answer`, as printed by a solver, matches this answer.
+    pub fn matches(&self, answer: &str) -> bool {
+        match self {
+            Answer::Integer(value) => answer.parse::<i64>() == Ok(*value),
+            Answer::Text(value) => answer == value,
+        }
+    }
+}
+
+/// The known answers for a day. Parts without a known answer are `None`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+    pub wrong_one: Vec<Answer>,
+    pub wrong_two: Vec<Answer>,
+}
+
+impl Answers {
+    pub fn path(year: u16, day: u8) -> io::Result<PathBuf> {
+        Ok(crate::year_dir(year)?
+            .join("answers")
+            .join(format!("{:02}.toml", day)))
+    }
+
+    /// Loads the answers for `day` of `year`. A missing file means that no answers are known yet.
+    pub fn load(year: u16, day: u8) -> Result<Self, String> {
+        let path = Self::path(year, day).map_err(|e| e.to_string())?;
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let table = match contents.parse::<toml::Value>() {
+            Ok(toml::Value::Table(table)) => table,
+            Ok(_) => return Err("expected a table".to_string()),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let mut answers = Answers::default();
+        for (key, value) in table {
+            match key.as_str() {
+                "part_one" => answers.part_one = Some(Answer::parse(&key, value)?),
+                "part_two" => answers.part_two = Some(Answer::parse(&key, value)?),
+                "wrong_one" | "wrong_two" => {
+                    let values = match value {
+                        toml::Value::Array(values) => values,
+                        value => {
+                            return Err(format!(
+                                "`{}` must be an array, found {}",
+                                key,
+                                value.type_str()
+                            ))
+                        }
+                    };
+                    let wrong = values
+                        .into_iter()
+                        .map(|value| Answer::parse(&key, value))
+                        .collect::<Result<_, _>>()?;
+                    if key == "wrong_one" {
+                        answers.wrong_one = wrong;
+                    } else {
+                        answers.wrong_two = wrong;
+                    }
+                }
+                _ => return Err(format!("unknown key `{}`", key)),
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Writes the answers to `path`, in the format that [`Answers::parse`] reads.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut table = toml::value::Table::new();
+        let parts = [
+            ("part_one", &self.part_one, &self.wrong_one),
+            ("part_two", &self.part_two, &self.wrong_two),
+        ];
+        for (key, answer, wrong) in parts {
+            if let Some(answer) = answer {
+                table.insert(key.to_string(), answer.to_toml());
+            }
+            if !wrong.is_empty() {
+                table.insert(
+                    key.replace("part", "wrong"),
+                    toml::Value::Array(wrong.iter().map(Answer::to_toml).collect()),
+                );
+            }
+        }
+
+        let contents = toml::to_string(&table).map_err(|e| e.to_string())?;
+        fs::create_dir_all(path.parent().unwrap_or(path))
+            .and_then(|_| fs::write(path, contents))
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_one.as_ref(),
+            2 => self.part_two.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Answers to `part` that were rejected on submission.
+    pub fn wrong(&self, part: u8) -> &[Answer] {
+        match part {
+            1 => &self.wrong_one,
+            2 => &self.wrong_two,
+            _ => &[],
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: Answer) {
+        match part {
+            1 => self.part_one = Some(answer),
+            2 => self.part_two = Some(answer),
+            _ => {}
+        }
+    }
+
+    pub fn add_wrong(&mut self, part: u8, answer: Answer) {
+        match part {
+            1 => self.wrong_one.push(answer),
+            2 => self.wrong_two.push(answer),
+            _ => {}
+        }
+    }
+}
+
+/// How a part's outcome compares to its known answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// The part did not produce the known answer, including when it is unsolved or errored.
+    Incorrect {
+        expected: Answer,
+    },
+    /// There is no known answer for this part.
+    Unknown,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&Answer>, outcome: &Outcome) -> Self {
+        match (expected, outcome) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Outcome::Solved(answer)) if expected.matches(answer) => {
+                Verdict::Correct
+            }
+            (Some(expected), _) => Verdict::Incorrect {
+                expected: expected.clone(),
+            },
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect { .. } => "incorrect",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("part_one = 24000\npart_two = \"CMZ\"\n").unwrap();
+        assert_eq!(answers.get(1), Some(&Answer::Integer(24000)));
+        assert_eq!(answers.get(2), Some(&Answer::Text("CMZ".to_string())));
+
+        assert_eq!(Answers::parse("part_two = 1").unwrap().get(1), None);
+        assert!(Answers::parse("part_one = 1.5").is_err());
+        assert!(Answers::parse("part_three = 1").is_err());
+
+        let answers = Answers::parse("wrong_two = [1, \"CMZ\"]").unwrap();
+        assert_eq!(answers.wrong(1), &[]);
+        assert_eq!(
+            answers.wrong(2),
+            &[Answer::Integer(1), Answer::Text("CMZ".to_string())]
+        );
+        assert!(Answers::parse("wrong_one = 1").is_err());
+    }
+
+    #[test]
+    fn test_save_answers() {
+        let mut answers = Answers::default();
+        answers.set(1, Answer::from_output("24000"));
+        answers.add_wrong(2, Answer::from_output("CMZ"));
+        answers.add_wrong(2, Answer::from_output("-3"));
+
+        let path = std::env::temp_dir().join(format!("answers-{}.toml", std::process::id()));
+        answers.save(&path).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(contents, "part_one = 24000\nwrong_two = [\"CMZ\", -3]\n");
+        assert_eq!(Answers::parse(&contents), Ok(answers));
+    }
+
+    #[test]
+    fn test_verdict() {
+        let solved = |answer: &str| Outcome::Solved(answer.to_string());
+        let integer = Answer::Integer(42);
+        let text = Answer::Text("42".to_string());
+
+        assert_eq!(
+            Verdict::check(Some(&integer), &solved("42")),
+            Verdict::Correct
+        );
+        assert_eq!(Verdict::check(Some(&text), &solved("42")), Verdict::Correct);
+        assert_eq!(
+            Verdict::check(Some(&integer), &solved("43")),
+            Verdict::Incorrect {
+                expected: integer.clone()
+            }
+        );
+        assert_eq!(
+            Verdict::check(Some(&integer), &Outcome::Unsolved),
+            Verdict::Incorrect { expected: integer }
+        );
+        assert_eq!(Verdict::check(None, &solved("42")), Verdict::Unknown);
+    }
+}
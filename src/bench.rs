@@ -0,0 +1,173 @@
+use std::time::{Duration, Instant};
+
+use crate::solution::{Outcome, PartResult};
+
+/// Upper bound on the number of timed iterations, so that very fast parts don't collect an
+/// unbounded number of samples within the time budget.
+const MAX_ITERATIONS: usize = 1_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs before sampling starts.
+    pub warmup: u32,
+    /// How long to keep sampling. At least one sample is always taken.
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Timing statistics over repeated runs of a part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+
+        let mean_ns = samples.iter().map(|s| s.as_nanos() as f64).sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            samples
+                .iter()
+                .map(|s| (s.as_nanos() as f64 - mean_ns).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0_f64
+        };
+
+        Stats {
+            iterations: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean_ns.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Runs a part once for its answer, then benchmarks it according to `config`. `run` is usually
+/// [`solve_catching`](crate::solution::solve_catching), or solves a part of a parsed input.
+///
+/// Only solved parts are benchmarked. If any iteration disagrees with the first answer, the
+/// result is turned into an [`Outcome::Error`] and no statistics are returned.
+pub fn bench_part(
+    run: impl Fn() -> PartResult,
+    config: &BenchConfig,
+) -> (PartResult, Option<Stats>) {
+    let result = run();
+    let expected = match &result.outcome {
+        Outcome::Solved(answer) => answer.clone(),
+        _ => return (result, None),
+    };
+
+    let check = |run: PartResult| -> Result<Duration, PartResult> {
+        let message = match run.outcome {
+            Outcome::Solved(answer) if answer == expected => return Ok(run.elapsed),
+            Outcome::Solved(answer) => format!(
+                "answer changed between iterations: {:?} became {:?}",
+                expected, answer
+            ),
+            Outcome::Unsolved => format!(
+                "answer changed between iterations: {:?} became unsolved",
+                expected
+            ),
+            Outcome::Error(message) => message,
+            Outcome::TimedOut(limit) => format!("timed out after {:.2?}", limit),
+        };
+        Err(PartResult {
+            outcome: Outcome::Error(message),
+            elapsed: result.elapsed,
+            alloc: result.alloc,
+        })
+    };
+
+    match sample(config, || check(run())) {
+        Ok(stats) => (result, Some(stats)),
+        Err(mismatch) => (mismatch, None),
+    }
+}
+
+/// Calls `run` `config.warmup` times, then samples the durations it returns until the budget is
+/// used up. Stops at the first error.
+pub fn sample<E>(
+    config: &BenchConfig,
+    mut run: impl FnMut() -> Result<Duration, E>,
+) -> Result<Stats, E> {
+    for _ in 0..config.warmup {
+        run()?;
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+    while samples.is_empty() || (start.elapsed() < config.budget && samples.len() < MAX_ITERATIONS)
+    {
+        samples.push(run()?);
+    }
+    Ok(Stats::from_samples(&mut samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+    use crate::solution::{solve_catching, Day};
+
+    #[test]
+    fn test_stats_from_samples() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_nanos);
+        let stats = Stats::from_samples(&mut samples);
+
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(2));
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        // sample stddev of [1, 2, 3, 4] is ~1.29
+        assert_eq!(stats.stddev, Duration::from_nanos(1));
+    }
+
+    #[test]
+    fn test_bench_part_detects_changing_answers() {
+        static CALLS: AtomicU32 = AtomicU32::new(0);
+        let day = Day {
+            year: 2022,
+            day: 1,
+            part_one: |_| Some(CALLS.fetch_add(1, Ordering::Relaxed).min(5)),
+            part_two: |_| Some(1),
+        };
+        let config = BenchConfig {
+            warmup: 0,
+            budget: Duration::from_millis(10),
+        };
+
+        let (result, stats) = bench_part(|| solve_catching(&day, 1, ""), &config);
+        assert!(matches!(result.outcome, Outcome::Error(_)));
+        assert_eq!(stats, None);
+
+        let (result, stats) = bench_part(|| solve_catching(&day, 2, ""), &config);
+        assert_eq!(result.answer(), Some("1"));
+        assert!(stats.unwrap().iterations >= 1);
+    }
+}
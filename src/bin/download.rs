@@ -2,104 +2,104 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
 use std::{fs, process};
 
+use advent_of_code::client::Client;
+use advent_of_code::years;
+
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: u16,
+    force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(years::latest),
+        force: args.contains("--force"),
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
-    }
-}
-
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
-}
-
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
-    }
-
-    let mut cmd_args = vec![];
+    let input_path = match advent_of_code::year_dir(args.year) {
+        Ok(dir) => dir.join("inputs").join(format!("{:02}.txt", args.day)),
+        Err(e) => {
+            eprintln!("could not locate the puzzle data: {}", e);
+            process::exit(1);
+        }
+    };
 
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    let has_input = fs::metadata(&input_path).is_ok_and(|metadata| metadata.len() > 0);
+    if has_input && !args.force {
+        eprintln!(
+            "\"{}\" already contains an input. Pass --force to overwrite it.",
+            input_path.display()
+        );
+        process::exit(1);
     }
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
 
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
+    // --force also replaces a cached input, e.g. one that was cut short or belongs to another
+    // account.
+    let cached = if args.force {
+        None
+    } else {
+        client.cached_input(args.year, args.day)
+    };
+    let input = if let Some(input) = cached {
+        println!("Using cached input for day {}, {}...", args.day, args.year);
+        input
+    } else {
+        println!(
+            "Downloading input for day {}, {} from {}...",
+            args.day, args.year, client.base_url
+        );
+        match client.download_input(args.year, args.day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("could not download input: {}", e);
+                process::exit(1);
             }
         }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
-        }
-    }
+    };
 
-    match fs::copy(&tmp_file_path, &input_path) {
+    // staged next to the target with a unique name, so concurrent downloads do not clobber
+    // each other.
+    let staging_path = input_path.with_extension(format!("txt.{}.tmp", process::id()));
+    let written = fs::create_dir_all(input_path.parent().unwrap())
+        .and_then(|_| fs::write(&staging_path, input))
+        .and_then(|_| fs::rename(&staging_path, &input_path));
+    match written {
         Ok(_) => {
             println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+            println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            );
         }
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            let _ = fs::remove_file(&staging_path);
+            eprintln!("could not write input file: {}", e);
+            process::exit(1);
         }
     }
 }
@@ -2,71 +2,396 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::years;
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    ops::Range,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+const REGISTRY_TEMPLATE: &str = r###"&Day {
+    year: YEAR,
+    day: DAY,
+    part_one: dayDAY_PADDED::part_one,
+    part_two: dayDAY_PADDED::part_two,
+}"###;
+
+/// The registry entry of modules that parse their input once, see [`defines_parse`].
+const PARSED_REGISTRY_TEMPLATE: &str = r###"&ParsedDay {
+    year: YEAR,
+    day: DAY,
+    parse: dayDAY_PADDED::parse,
+    part_one: dayDAY_PADDED::part_one,
+    part_two: dayDAY_PADDED::part_two,
+}"###;
+
+const BIN_TEMPLATE: &str = r###"//! Runs day DAY of the latest year, or of `--year`, like `cargo solve DAY_PADDED` does.
+
+fn main() {
+    advent_of_code::runner::solve_main(Some(DAY));
 }
+"###;
+
+const YEAR_TEMPLATE: &str = r###"use crate::solution::{Day, Solution};
+
+/// Every solved day of YEAR, in order. Registered in `src/years.rs`.
+pub static SOLUTIONS: &[&dyn Solution] = &[];
+"###;
+
+const YEARS_TEMPLATE: &str = r###"Year {
+    year: YEAR,
+    solutions: crate::yYEAR::SOLUTIONS,
+}"###;
+
+/// Holds the module templates, selected with `--template <name>` as `<name>.rs`.
+const TEMPLATES_DIR: &str = "templates";
+const DEFAULT_TEMPLATE: &str = "default";
+
+const LIB_PATH: &str = "src/lib.rs";
+const YEARS_PATH: &str = "src/years.rs";
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+struct Args {
+    day: u8,
+    year: u16,
+    template: String,
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(years::latest);
+    let template = args
+        .opt_value_from_str(["-t", "--template"])?
+        .unwrap_or_else(|| DEFAULT_TEMPLATE.to_string());
+    Ok(Args {
+        day: args.free_from_str()?,
+        year,
+        template,
+    })
+}
+
+fn safe_create_file(path: &str) -> Result<File, io::Error> {
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
+
+fn create_file(path: &str) -> Result<File, io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Reads the module template `name`, or lists the available ones in the error.
+fn load_template(name: &str) -> Result<String, String> {
+    let path = format!("{}/{}.rs", TEMPLATES_DIR, name);
+    fs::read_to_string(&path).map_err(|e| {
+        let mut available: Vec<String> = fs::read_dir(TEMPLATES_DIR)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let file_name = entry.ok()?.file_name().into_string().ok()?;
+                Some(file_name.strip_suffix(".rs")?.to_string())
+            })
+            .collect();
+        available.sort();
+        format!(
+            "\"{}\": {}. Available templates: {}",
+            path,
+            e,
+            available.join(", ")
+        )
+    })
+}
+
+fn fill_template(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("DAY_PADDED", &format!("{:02}", day))
+        .replace("DAY", &day.to_string())
+        .replace("YEAR", &year.to_string())
+}
+
+/// Whether a module template has a `parse` step that its parts take the result of.
+fn defines_parse(module_template: &str) -> bool {
+    module_template
+        .lines()
+        .any(|line| line.starts_with("pub fn parse("))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+/// Adds the module declaration `decl` to `contents`, keeping the `pub mod` lines in the order
+/// rustfmt sorts them in. Without any, `decl` goes below the imports at the top.
+fn insert_mod(contents: &str, decl: &str) -> String {
+    let mut position = None;
+    let mut offset = 0;
+    for line in contents.split_inclusive('\n') {
+        if line.starts_with("pub mod ") {
+            if line.trim_end() > decl {
+                position = Some(offset);
+                break;
+            }
+            position = Some(offset + line.len());
+        }
+        offset += line.len();
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+    match position {
+        Some(position) => format!(
+            "{}{}\n{}",
+            &contents[..position],
+            decl,
+            &contents[position..]
+        ),
+        None => {
+            let position = contents.find("\n\n").map_or(0, |pos| pos + 2);
+            format!(
+                "{}{}\n\n{}",
+                &contents[..position],
+                decl,
+                &contents[position..]
+            )
+        }
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// The range between the brackets of the static list `name` in `contents`.
+fn list_range(contents: &str, name: &str) -> Result<Range<usize>, io::Error> {
+    let not_found = || invalid_data(format!("could not find `{}`", name));
+    let declaration = contents
+        .find(&format!("static {}:", name))
+        .ok_or_else(not_found)?;
+    let start = declaration + contents[declaration..].find("= &[").ok_or_else(not_found)? + 4;
+
+    let mut depth = 0;
+    for (offset, c) in contents[start..].char_indices() {
+        match c {
+            '{' | '[' | '(' => depth += 1,
+            ']' if depth == 0 => return Ok(start..start + offset),
+            '}' | ']' | ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    Err(invalid_data(format!("could not find end of `{}`", name)))
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Splits the items of a list into entries, each indented as if it started a line.
+fn split_entries(items: &str) -> Vec<String> {
+    let mut entries = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (offset, c) in items.char_indices().chain([(items.len(), ',')]) {
+        match c {
+            '{' | '[' | '(' => depth += 1,
+            '}' | ']' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                let entry = items[start..offset].trim();
+                if !entry.is_empty() {
+                    entries.push(dedent(entry));
+                }
+                start = offset + 1;
+            }
+            _ => {}
+        }
+    }
+    entries
+}
+
+/// Removes the indentation that all lines but the first, which is already trimmed, share.
+fn dedent(entry: &str) -> String {
+    let indent = entry
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut lines = entry.lines();
+    let first = lines.next().unwrap_or_default().to_string();
+    lines.fold(first, |dedented, line| {
+        format!("{}\n{}", dedented, line.get(indent..).unwrap_or_default())
+    })
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// The items of a list of `entries`, laid out as rustfmt does: a single entry hugs the brackets,
+/// e.g. `&[Year {\n    ..\n}]`, while several get a line each.
+fn join_entries(entries: &[String]) -> String {
+    match entries {
+        [] => String::new(),
+        [entry] => entry.clone(),
+        _ => {
+            let mut items = String::new();
+            for entry in entries {
+                items.push('\n');
+                for line in entry.lines() {
+                    if !line.is_empty() {
+                        items.push_str("    ");
+                    }
+                    items.push_str(line);
+                    items.push('\n');
+                }
+                items.pop();
+                items.push(',');
+            }
+            items.push('\n');
+            items
+        }
+    }
+}
+
+/// The value of the numeric `field` of an entry, e.g. the `day` of a `SOLUTIONS` entry.
+fn entry_key(entry: &str, field: &str) -> Option<u16> {
+    let prefix = format!("{}: ", field);
+    entry.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix(&prefix)?
+            .strip_suffix(',')?
+            .parse()
+            .ok()
+    })
+}
+
+/// Adds `entry` to the static list `name` in `contents`, keeping the entries ordered by `field`.
+fn insert_list_entry(
+    contents: &str,
+    name: &str,
+    field: &str,
+    entry: &str,
+) -> Result<String, io::Error> {
+    let range = list_range(contents, name)?;
+    let mut entries = split_entries(&contents[range.clone()]);
+    entries.push(entry.to_string());
+    entries.sort_by_key(|entry| entry_key(entry, field));
+    Ok(format!(
+        "{}{}{}",
+        &contents[..range.start],
+        join_entries(&entries),
+        &contents[range.end..]
+    ))
+}
+
+/// Declares the module of a new year in `lib.rs` and adds it to `YEARS`.
+fn register_year(year: u16) -> Result<(), io::Error> {
+    let lib = fs::read_to_string(LIB_PATH)?;
+    fs::write(LIB_PATH, insert_mod(&lib, &format!("pub mod y{};", year)))?;
+
+    let years = fs::read_to_string(YEARS_PATH)?;
+    let entry = fill_template(YEARS_TEMPLATE, year, 0);
+    fs::write(
+        YEARS_PATH,
+        insert_list_entry(&years, "YEARS", "year", &entry)?,
+    )
+}
+
+/// Imports exactly the kinds of solution that the `SOLUTIONS` of `registry` use, so that adding
+/// the first `ParsedDay` imports it and the import is never unused.
+fn update_imports(registry: &str) -> String {
+    let mut imports = vec![];
+    for kind in ["Day", "ParsedDay"] {
+        if registry.contains(&format!("&{} {{", kind)) {
+            imports.push(kind);
+        }
+    }
+    imports.push("Solution");
+    let import = format!("use crate::solution::{{{}}};", imports.join(", "));
+
+    registry
+        .lines()
+        .map(|line| {
+            if line.starts_with("use crate::solution::") {
+                import.as_str()
+            } else {
+                line
+            }
+        })
+        .fold(String::new(), |updated, line| updated + line + "\n")
+}
+
+/// Adds the day's module declaration and its `SOLUTIONS` entry to the year's registry.
+fn register_day(registry_path: &str, year: u16, day: u8, parsed: bool) -> Result<(), io::Error> {
+    let registry = fs::read_to_string(registry_path)?;
+    let registry = insert_mod(&registry, &format!("pub mod day{:02};", day));
+
+    let template = if parsed {
+        PARSED_REGISTRY_TEMPLATE
+    } else {
+        REGISTRY_TEMPLATE
+    };
+    let entry = fill_template(template, year, day);
+    let registry = insert_list_entry(&registry, "SOLUTIONS", "day", &entry)?;
+    fs::write(registry_path, update_imports(&registry))
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let Args {
+        day,
+        year,
+        template,
+    } = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            eprintln!(
+                "Need to specify a day (as integer). example: `cargo scaffold 7 --year 2022`"
+            );
+            process::exit(1);
+        }
+    };
+
+    let module_template = match load_template(&template) {
+        Ok(module_template) => module_template,
+        Err(e) => {
+            eprintln!("Failed to load template {}", e);
             process::exit(1);
         }
     };
 
     let day_padded = format!("{:02}", day);
+    let year_dir = format!("src/y{}", year);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let registry_path = format!("{}.rs", year_dir);
+    let input_path = format!("{}/inputs/{}.txt", year_dir, day_padded);
+    let example_path = format!("{}/examples/{}.txt", year_dir, day_padded);
+    let module_path = format!("{}/day{}.rs", year_dir, day_padded);
+    let bin_path = format!("src/bin/{}.rs", day_padded);
+
+    for dir in ["inputs", "examples", "answers"] {
+        if let Err(e) = fs::create_dir_all(format!("{}/{}", year_dir, dir)) {
+            eprintln!("Failed to create directories for {}: {}", year, e);
+            process::exit(1);
+        }
+    }
+
+    if years::find(year).is_none() {
+        let mut file = match safe_create_file(&registry_path) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("Failed to create registry file: {}", e);
+                process::exit(1);
+            }
+        };
+
+        if let Err(e) = file.write_all(fill_template(YEAR_TEMPLATE, year, day).as_bytes()) {
+            eprintln!("Failed to write registry contents: {}", e);
+            process::exit(1);
+        }
+
+        match register_year(year) {
+            Ok(_) => {
+                println!(
+                    "Created registry \"{}\" and registered {} in \"{}\"",
+                    &registry_path, year, YEARS_PATH
+                );
+            }
+            Err(e) => {
+                eprintln!("Failed to register year: {}", e);
+                process::exit(1);
+            }
+        }
+    }
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,9 +401,12 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(fill_template(&module_template, year, day).as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!(
+                "Created module file \"{}\" from template \"{}\"",
+                &module_path, &template
+            );
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -86,6 +414,34 @@ fn main() {
         }
     }
 
+    match register_day(&registry_path, year, day, defines_parse(&module_template)) {
+        Ok(_) => {
+            println!("Registered day in \"{}\"", &registry_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to register day: {}", e);
+            process::exit(1);
+        }
+    }
+
+    // the binary runs the day of every year, so it only needs to be created once.
+    match safe_create_file(&bin_path) {
+        Ok(mut file) => match file.write_all(fill_template(BIN_TEMPLATE, year, day).as_bytes()) {
+            Ok(_) => {
+                println!("Created binary \"{}\"", &bin_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to write binary contents: {}", e);
+                process::exit(1);
+            }
+        },
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+        Err(e) => {
+            eprintln!("Failed to create binary file: {}", e);
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
@@ -108,7 +464,7 @@ fn main() {
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        &day_padded, year
     );
 }
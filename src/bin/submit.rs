@@ -0,0 +1,126 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::process;
+
+use advent_of_code::answers::{Answer, Answers};
+use advent_of_code::client::{Client, SubmitResponse};
+use advent_of_code::runner::parse_part;
+use advent_of_code::solution::{solve_catching, Outcome};
+use advent_of_code::years;
+
+struct Args {
+    year: u16,
+    day: u8,
+    part: u8,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(years::latest),
+        day: args.free_from_str()?,
+        part: args.free_from_fn(parse_part)?,
+    })
+}
+
+fn exit_with_error(message: impl AsRef<str>) -> ! {
+    eprintln!("{}", message.as_ref());
+    process::exit(1);
+}
+
+fn main() {
+    let Args { year, day, part } = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(format!(
+            "Failed to process arguments: {}. example: `cargo submit 7 1 --year 2022`",
+            e
+        )),
+    };
+
+    let solution = years::find_day(year, day).unwrap_or_else(|| {
+        exit_with_error(format!("Day {} of {} has no solution yet.", day, year))
+    });
+    let input = advent_of_code::try_read_file(year, "inputs", day)
+        .unwrap_or_else(|e| exit_with_error(format!("could not open input file: {}", e)));
+
+    let answer = match solve_catching(solution, part, &input).outcome {
+        Outcome::Solved(answer) => answer,
+        Outcome::Unsolved => exit_with_error(format!("Part {} is not solved yet.", part)),
+        Outcome::Error(e) => exit_with_error(format!("Part {} failed: {}", part, e)),
+        Outcome::TimedOut(_) => unreachable!("parts are not run with a timeout here"),
+    };
+
+    let answers_path = Answers::path(year, day).unwrap_or_else(|e| exit_with_error(e.to_string()));
+    let mut answers = Answers::load(year, day).unwrap_or_else(|e| exit_with_error(e));
+
+    // guard against sending what is already known.
+    if let Some(known) = answers.get(part) {
+        if known.matches(&answer) {
+            println!("{} was already accepted, nothing to submit.", answer);
+            return;
+        }
+        exit_with_error(format!(
+            "Part {} was already accepted as {}, but the solver returned {}.",
+            part, known, answer
+        ));
+    }
+    if answers
+        .wrong(part)
+        .iter()
+        .any(|wrong| wrong.matches(&answer))
+    {
+        exit_with_error(format!(
+            "{} was already rejected for part {}, not sending it again.",
+            answer, part
+        ));
+    }
+
+    let client = Client::from_env().unwrap_or_else(|e| exit_with_error(e));
+    println!(
+        "Submitting {} for day {} part {}, {} to {}...",
+        answer, day, part, year, client.base_url
+    );
+    let response = client
+        .submit(year, day, part, &answer)
+        .unwrap_or_else(|e| exit_with_error(format!("could not submit answer: {}", e)));
+
+    match response {
+        SubmitResponse::Correct => {
+            answers.set(part, Answer::from_output(&answer));
+            answers
+                .save(&answers_path)
+                .unwrap_or_else(|e| exit_with_error(format!("could not record answer: {}", e)));
+            println!("---");
+            println!(
+                "⭐️ That's the right answer! Recorded it in \"{}\".",
+                answers_path.display()
+            );
+        }
+        SubmitResponse::Incorrect { hint } => {
+            answers.add_wrong(part, Answer::from_output(&answer));
+            if let Err(e) = answers.save(&answers_path) {
+                eprintln!("could not record the rejected answer: {}", e);
+            }
+            match hint {
+                Some(hint) => {
+                    exit_with_error(format!("❌ That's not the right answer, it is {}.", hint))
+                }
+                None => exit_with_error("❌ That's not the right answer."),
+            }
+        }
+        SubmitResponse::TooSoon { wait } => exit_with_error(format!(
+            "You submitted an answer too recently. Wait {} before trying again.",
+            wait.as_deref().unwrap_or("a little")
+        )),
+        SubmitResponse::WrongLevel => exit_with_error(
+            "This part is not open. Did you already complete it, or is part 1 missing?",
+        ),
+        SubmitResponse::Unknown(text) => {
+            exit_with_error(format!("Unexpected response from the website: {}", text))
+        }
+    }
+}
@@ -0,0 +1,416 @@
+//! A small HTTP client for the Advent of Code website.
+//!
+//! It is configured through the environment:
+//!
+//! - `AOC_SESSION` holds the session cookie. Without it, the cookie is read from the file at
+//!   `AOC_SESSION_FILE`, which defaults to `~/.adventofcode.session`.
+//! - `AOC_BASE_URL` points the client at another server, e.g. a local stand-in for tests.
+//! - `AOC_CACHE_DIR` moves the cache of downloaded inputs, which defaults to `target/aoc-cache`.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const CACHE_DIR_ENV: &str = "AOC_CACHE_DIR";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_SESSION_FILE: &str = ".adventofcode.session";
+const DEFAULT_CACHE_DIR: &str = "target/aoc-cache";
+
+/// Requests made through any client are at least this far apart, across processes.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Holds the time of the latest request in its modification time.
+const LAST_REQUEST_FILE: &str = "last-request";
+
+const USER_AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/fspoettel/advent-of-code-rust)"
+);
+
+pub struct Client {
+    pub base_url: String,
+    /// The session cookie, only needed once a request is made.
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+    pub min_interval: Duration,
+}
+
+impl Client {
+    pub fn from_env() -> Result<Self, String> {
+        Ok(Client {
+            base_url: non_empty_var(BASE_URL_ENV).unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            session: read_session()?,
+            cache_dir: non_empty_var(CACHE_DIR_ENV)
+                .map_or_else(|| PathBuf::from(DEFAULT_CACHE_DIR), PathBuf::from),
+            min_interval: MIN_INTERVAL,
+        })
+    }
+
+    fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(format!("y{}", year))
+            .join(format!("{:02}.txt", day))
+    }
+
+    /// The input of a day from an earlier download, if there is one.
+    pub fn cached_input(&self, year: u16, day: u8) -> Option<String> {
+        fs::read_to_string(self.cache_path(year, day))
+            .ok()
+            .filter(|input| !input.is_empty())
+    }
+
+    /// The input of a day, from the cache or else downloaded and cached.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        match self.cached_input(year, day) {
+            Some(input) => Ok(input),
+            None => self.download_input(year, day),
+        }
+    }
+
+    /// Downloads the input of a day, even if it is cached, and replaces the cached copy.
+    pub fn download_input(&self, year: u16, day: u8) -> Result<String, String> {
+        let input = self.get(&format!("/{}/day/{}/input", year, day))?;
+        let path = self.cache_path(year, day);
+        let cached = fs::create_dir_all(self.cache_dir.join(format!("y{}", year)))
+            .and_then(|_| fs::write(&path, &input));
+        if let Err(e) = cached {
+            eprintln!("could not cache input in {}: {}", path.display(), e);
+        }
+        Ok(input)
+    }
+
+    /// Submits `answer` to `part` of a day and reports how the website took it.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitResponse, String> {
+        let html = self.post(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        Ok(SubmitResponse::parse(&html))
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        self.send("GET", path, None)
+    }
+
+    /// Sends `form` url-encoded.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        self.send("POST", path, Some(form))
+    }
+
+    fn send(
+        &self,
+        method: &str,
+        path: &str,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, String> {
+        let url = format!("{}{}", self.base_url.trim_end_matches('/'), path);
+        let session = self.session.as_ref().ok_or_else(|| {
+            format!(
+                "no session cookie: set {} or put it into ~/{}",
+                SESSION_ENV, DEFAULT_SESSION_FILE
+            )
+        })?;
+
+        self.throttle();
+        let request = ureq::request(method, &url)
+            .set("User-Agent", USER_AGENT)
+            .set("Cookie", &format!("session={}", session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        }
+        .map_err(|e| describe_error(&url, e))?;
+        response
+            .into_string()
+            .map_err(|e| format!("could not read response from {}: {}", url, e))
+    }
+
+    /// Sleeps until `min_interval` has passed since the latest request, then records this one.
+    fn throttle(&self) {
+        let marker = self.cache_dir.join(LAST_REQUEST_FILE);
+        let since_last = fs::metadata(&marker)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+        if let Some(wait) = since_last.and_then(|elapsed| self.min_interval.checked_sub(elapsed)) {
+            eprintln!("Waiting {:.1}s between requests...", wait.as_secs_f64());
+            thread::sleep(wait);
+        }
+
+        let recorded = fs::create_dir_all(&self.cache_dir).and_then(|_| fs::write(&marker, ""));
+        if let Err(e) = recorded {
+            eprintln!("could not record request in {}: {}", marker.display(), e);
+        }
+    }
+}
+
+/// How the website took a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitResponse {
+    Correct,
+    /// `hint` says whether the answer was too high or too low, if the website tells.
+    Incorrect {
+        hint: Option<&'static str>,
+    },
+    /// Answers were submitted too recently. `wait` is the remaining time, e.g. `"34s"`.
+    TooSoon {
+        wait: Option<String>,
+    },
+    /// The part is not open, usually because it was already solved.
+    WrongLevel,
+    /// A response that is not understood, as text.
+    Unknown(String),
+}
+
+impl SubmitResponse {
+    pub fn parse(html: &str) -> Self {
+        let text = article_text(html);
+        if text.contains("That's the right answer") {
+            SubmitResponse::Correct
+        } else if text.contains("That's not the right answer") {
+            let hint = ["too high", "too low"]
+                .into_iter()
+                .find(|hint| text.contains(&format!("your answer is {}", hint)));
+            SubmitResponse::Incorrect { hint }
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            SubmitResponse::TooSoon { wait }
+        } else if text.contains("You don't seem to be solving the right level") {
+            SubmitResponse::WrongLevel
+        } else {
+            SubmitResponse::Unknown(text)
+        }
+    }
+}
+
+/// The text of the `<article>` in `html`, or of the whole page if there is none.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn non_empty_var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.trim().is_empty())
+}
+
+fn read_session() -> Result<Option<String>, String> {
+    if let Some(session) = non_empty_var(SESSION_ENV) {
+        return Ok(Some(session.trim().to_string()));
+    }
+
+    let path = match non_empty_var(SESSION_FILE_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => match non_empty_var("HOME").or_else(|| non_empty_var("USERPROFILE")) {
+            Some(home) => PathBuf::from(home).join(DEFAULT_SESSION_FILE),
+            None => return Ok(None),
+        },
+    };
+    match fs::read_to_string(&path) {
+        Ok(session) => Ok(Some(session.trim().to_string()).filter(|s| !s.is_empty())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+    }
+}
+
+fn describe_error(url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(404, _) => format!("{} was not found, is the puzzle out yet?", url),
+        ureq::Error::Status(400 | 500, _) => format!(
+            "{} rejected the request, the session cookie may have expired",
+            url
+        ),
+        ureq::Error::Status(code, _) => format!("{} responded with status {}", url, code),
+        ureq::Error::Transport(e) => format!("could not reach {}: {}", url, e),
+    }
+}
+
+/// A local stand-in for the website that answers a single request.
+#[cfg(test)]
+pub(crate) mod stand_in {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves `body` with `status` once. Returns the base URL and a handle that yields the request.
+    pub fn serve_once(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut payload = vec![0; content_length];
+            reader.read_exact(&mut payload).unwrap();
+            request.push_str(&String::from_utf8(payload).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(base_url: String, cache_dir: &str) -> Client {
+        let cache_dir = env::temp_dir().join(format!("{}-{}", cache_dir, std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        Client {
+            base_url,
+            session: Some("cookie".to_string()),
+            cache_dir,
+            min_interval: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, request) = stand_in::serve_once(200, "1000\n2000\n");
+        let client = client(base_url, "aoc-client-input");
+
+        assert_eq!(client.input(2022, 1), Ok("1000\n2000\n".to_string()));
+        let request = request.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=cookie\r\n"));
+
+        // served from the cache, the stand-in is gone by now.
+        assert_eq!(client.input(2022, 1), Ok("1000\n2000\n".to_string()));
+        fs::remove_dir_all(&client.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_download_input() {
+        let (base_url, request) = stand_in::serve_once(200, "3000\n");
+        let client = client(base_url, "aoc-client-download");
+        fs::create_dir_all(client.cache_dir.join("y2022")).unwrap();
+        fs::write(client.cache_path(2022, 1), "1000\n").unwrap();
+
+        assert_eq!(client.download_input(2022, 1), Ok("3000\n".to_string()));
+        request.join().unwrap();
+        assert_eq!(client.cached_input(2022, 1), Some("3000\n".to_string()));
+        fs::remove_dir_all(&client.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_input_not_found() {
+        let (base_url, request) = stand_in::serve_once(404, "Not Found");
+        let client = client(base_url, "aoc-client-not-found");
+
+        assert!(client
+            .input(2022, 25)
+            .unwrap_err()
+            .ends_with("/2022/day/25/input was not found, is the puzzle out yet?"));
+        request.join().unwrap();
+        assert_eq!(client.cached_input(2022, 25), None);
+        fs::remove_dir_all(&client.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, request) = stand_in::serve_once(
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        );
+        let client = client(base_url, "aoc-client-submit");
+
+        assert_eq!(
+            client.submit(2022, 5, 2, "MCD"),
+            Ok(SubmitResponse::Correct)
+        );
+        let request = request.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/5/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=MCD"));
+        fs::remove_dir_all(&client.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_submit_response() {
+        let article = |text: &str| format!("<html><article><p>{}</p></article></html>", text);
+
+        assert_eq!(
+            SubmitResponse::parse(&article(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            SubmitResponse::Incorrect {
+                hint: Some("too high")
+            }
+        );
+        assert_eq!(
+            SubmitResponse::parse(&article("That's not the right answer.")),
+            SubmitResponse::Incorrect { hint: None }
+        );
+        assert_eq!(
+            SubmitResponse::parse(&article(
+                "You gave an answer too recently. You have 34s left to wait. <a href=\"/2022/day/5\">[Return]</a>"
+            )),
+            SubmitResponse::TooSoon {
+                wait: Some("34s".to_string())
+            }
+        );
+        assert_eq!(
+            SubmitResponse::parse(&article(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            SubmitResponse::WrongLevel
+        );
+        assert_eq!(
+            SubmitResponse::parse("<p>Something <b>else</b>\n</p>"),
+            SubmitResponse::Unknown("Something else".to_string())
+        );
+    }
+}
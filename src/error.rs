@@ -0,0 +1,168 @@
+use std::fmt::{self, Display};
+
+use nom::error::ErrorKind;
+use nom::Parser;
+
+/// How much of the offending input is quoted in diagnostics.
+const SNIPPET_LEN: usize = 20;
+
+/// Why a part did not produce an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    /// The part is not solved yet. Reported as "not solved." rather than as an error.
+    Unsolved,
+    /// A parser failed at the given position. Lines and columns start at 1.
+    Parse {
+        line: usize,
+        column: usize,
+        kind: ErrorKind,
+        found: String,
+    },
+    /// Parsing succeeded, but left input that is not whitespace.
+    TrailingInput {
+        line: usize,
+        column: usize,
+        remaining: String,
+    },
+    /// The input parsed fine, but does not make sense, e.g. a puzzle without a solution.
+    Invalid(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Unsolved => write!(f, "not solved"),
+            SolveError::Parse {
+                line,
+                column,
+                kind,
+                found,
+            } => write!(
+                f,
+                "parse error at line {}, column {}: expected {}, found {:?}",
+                line,
+                column,
+                kind.description(),
+                found
+            ),
+            SolveError::TrailingInput {
+                line,
+                column,
+                remaining,
+            } => write!(
+                f,
+                "unparsed input at line {}, column {}: {:?}",
+                line, column, remaining
+            ),
+            SolveError::Invalid(message) => write!(f, "invalid input: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Runs `parser` on `input` and requires it to consume everything but trailing whitespace.
+///
+/// Unlike `parser(input).unwrap().1`, failures are reported with their line and column.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, O, nom::error::Error<&'a str>>,
+) -> Result<O, SolveError> {
+    match parser.parse(input) {
+        Ok((rest, output)) => {
+            let rest = rest.trim_start();
+            if rest.is_empty() {
+                Ok(output)
+            } else {
+                let (line, column) = locate(input, rest);
+                Err(SolveError::TrailingInput {
+                    line,
+                    column,
+                    remaining: snippet(rest),
+                })
+            }
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            let (line, column) = locate(input, e.input);
+            Err(SolveError::Parse {
+                line,
+                column,
+                kind: e.code,
+                found: snippet(e.input),
+            })
+        }
+        Err(nom::Err::Incomplete(_)) => {
+            let (line, column) = locate(input, "");
+            Err(SolveError::Parse {
+                line,
+                column,
+                kind: ErrorKind::Complete,
+                found: String::new(),
+            })
+        }
+    }
+}
+
+/// Finds the line and column at which `rest`, a suffix of `input`, starts.
+fn locate(input: &str, rest: &str) -> (usize, usize) {
+    let consumed = &input[..input.len() - rest.len()];
+    let line = consumed.matches('\n').count() + 1;
+    let line_start = consumed.rfind('\n').map_or(0, |pos| pos + 1);
+    let column = consumed[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+/// The start of `rest` up to the end of its line, shortened to [`SNIPPET_LEN`] characters.
+fn snippet(rest: &str) -> String {
+    let line = rest.lines().next().unwrap_or("");
+    match line.char_indices().nth(SNIPPET_LEN) {
+        Some((end, _)) => format!("{}...", &line[..end]),
+        None => line.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        character::complete::{digit1, line_ending},
+        multi::separated_list1,
+    };
+
+    use super::*;
+
+    fn parse_numbers(input: &str) -> Result<Vec<&str>, SolveError> {
+        parse_all(input, separated_list1(line_ending, digit1))
+    }
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(parse_numbers("1\n22\n"), Ok(vec!["1", "22"]));
+        assert_eq!(
+            parse_numbers("abc\n1"),
+            Err(SolveError::Parse {
+                line: 1,
+                column: 1,
+                kind: ErrorKind::Digit,
+                found: "abc".to_string()
+            })
+        );
+        assert_eq!(
+            parse_numbers("1\n22x3\n4"),
+            Err(SolveError::TrailingInput {
+                line: 2,
+                column: 3,
+                remaining: "x3".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            parse_numbers("1\n2\n 3 is a very long line indeed")
+                .unwrap_err()
+                .to_string(),
+            "unparsed input at line 3, column 2: \"3 is a very long lin...\""
+        );
+    }
+}
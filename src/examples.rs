@@ -0,0 +1,161 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::answers::{Answers, Verdict};
+use crate::solution::solve_catching;
+use crate::years;
+
+/// Separates the header of an example variant from its input.
+const FENCE: &str = "---";
+
+/// A named variant of a day's example input, stored as `src/yYYYY/examples/NN-<name>.txt`.
+///
+/// The file starts with a header that gives the expected answers in the format of the
+/// [answers files](crate::answers::Answer), followed by the input itself:
+///
+/// ```text
+/// ---
+/// part_one = 88
+/// part_two = 36
+/// ---
+/// R 5
+/// U 8
+/// ```
+///
+/// A test is generated for every variant, see `build.rs`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub answers: Answers,
+    pub input: String,
+}
+
+impl Example {
+    pub fn path(year: u16, day: u8, name: &str) -> io::Result<PathBuf> {
+        Ok(crate::year_dir(year)?
+            .join("examples")
+            .join(format!("{:02}-{}.txt", day, name)))
+    }
+
+    pub fn load(year: u16, day: u8, name: &str) -> Result<Self, String> {
+        let path = Self::path(year, day, name).map_err(|e| e.to_string())?;
+        let contents =
+            fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let (answers, input) =
+            Self::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(Example {
+            year,
+            day,
+            name: name.to_string(),
+            answers,
+            input: input.to_string(),
+        })
+    }
+
+    /// Splits `contents` into the expected answers and the input.
+    pub fn parse(contents: &str) -> Result<(Answers, &str), String> {
+        let mut lines = contents.split_inclusive('\n');
+        let header_start = match lines.next() {
+            Some(line) if line.trim_end() == FENCE => line.len(),
+            _ => return Err(format!("expected a `{}` line to open the header", FENCE)),
+        };
+
+        let mut offset = header_start;
+        for line in lines {
+            if line.trim_end() == FENCE {
+                let answers = Answers::parse(&contents[header_start..offset])?;
+                if answers == Answers::default() {
+                    return Err("the header does not contain any answers".to_string());
+                }
+                return Ok((answers, &contents[offset + line.len()..]));
+            }
+            offset += line.len();
+        }
+        Err(format!("expected a `{}` line to close the header", FENCE))
+    }
+
+    /// Runs the registered solution for the example's day and compares it to the header.
+    pub fn check(&self) -> Result<(), String> {
+        let solution = years::find_day(self.year, self.day)
+            .ok_or_else(|| format!("day {} of {} is not registered", self.day, self.year))?;
+
+        let failures: Vec<String> = [1, 2]
+            .into_iter()
+            .filter_map(|part| {
+                let expected = self.answers.get(part)?;
+                let result = solve_catching(solution, part, &self.input);
+                match Verdict::check(Some(expected), &result.outcome) {
+                    Verdict::Correct => None,
+                    _ => Some(format!(
+                        "part {}: expected {}, got {:?}",
+                        part, expected, result.outcome
+                    )),
+                }
+            })
+            .collect();
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "example {} {:02}-{}:\n{}",
+                self.year,
+                self.day,
+                self.name,
+                failures.join("\n")
+            ))
+        }
+    }
+}
+
+/// Entry point of the generated tests.
+pub fn check(year: u16, day: u8, name: &str) -> Result<(), String> {
+    Example::load(year, day, name)?.check()
+}
+
+#[cfg(test)]
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Answer;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let (answers, input) = Example::parse("---\npart_two = 36\n---\nR 5\nU 8\n").unwrap();
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some(&Answer::Integer(36)));
+        assert_eq!(input, "R 5\nU 8\n");
+
+        assert!(Example::parse("R 5\n").is_err());
+        assert!(Example::parse("---\npart_one = 1\nR 5\n").is_err());
+        assert!(Example::parse("---\n---\nR 5\n").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let example = |part_one| Example {
+            year: 2022,
+            day: 1,
+            name: "test".to_string(),
+            answers: Answers {
+                part_one: Some(Answer::Integer(part_one)),
+                ..Answers::default()
+            },
+            input: "1000\n2000\n\n4000\n".to_string(),
+        };
+
+        assert_eq!(example(4000).check(), Ok(()));
+        assert_eq!(
+            example(3000).check(),
+            Err("example 2022 01-test:\npart 1: expected 3000, got Solved(\"4000\")".to_string())
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! Branch and bound: a depth-first search for the best value of an optimisation problem, which
+//! skips every state that cannot beat the best value found so far.
+//!
+//! A problem is described by implementing [`Problem`] for it, then solved with [`solve`].
+
+use std::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+
+/// Whether a [`Problem`] looks for its largest or its smallest value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Goal {
+    Maximise,
+    Minimise,
+}
+
+impl Goal {
+    /// Whether `value` is strictly better than `other`.
+    pub fn is_better<V: Ord>(self, value: V, other: V) -> bool {
+        match self {
+            Goal::Maximise => value > other,
+            Goal::Minimise => value < other,
+        }
+    }
+}
+
+/// Which states [`solve`] remembers, to avoid exploring the same state twice.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Visited {
+    /// Remember no states, for problems whose states cannot repeat or that use
+    /// [`Problem::dominance`] instead. The best score of every dominance key is still kept, for
+    /// as long as the search runs.
+    Off,
+    #[default]
+    Unbounded,
+    /// Forget all remembered states once there are this many. This also bounds the states kept
+    /// for [`Problem::dominance`].
+    Bounded(usize),
+}
+
+pub trait Problem {
+    type State: Clone + Eq + Hash;
+    type Value: Copy + Ord;
+    /// What [`dominance`](Self::dominance) groups states by. Use `()` if it is not implemented.
+    type Key: Eq + Hash;
+
+    const GOAL: Goal;
+
+    /// The states that follow from `state`. Returns nothing if `state` is complete.
+    fn branch(&self, state: &Self::State) -> Vec<Self::State>;
+
+    /// An optimistic estimate of the best value reachable from `state`: never worse than the value
+    /// of `state` or of any state that follows from it.
+    fn bound(&self, state: &Self::State) -> Self::Value;
+
+    /// The value of `state`, if it is a solution.
+    fn value(&self, state: &Self::State) -> Option<Self::Value>;
+
+    /// A key and a score for `state`. Of the states with the same key, only those that beat the
+    /// score of every earlier one are explored, e.g. the same position reached with a better score.
+    fn dominance(&self, _state: &Self::State) -> Option<(Self::Key, Self::Value)> {
+        None
+    }
+}
+
+struct Search<'a, P: Problem> {
+    problem: &'a P,
+    visited: Visited,
+    best: Option<P::Value>,
+    seen: HashSet<P::State>,
+    scores: HashMap<P::Key, P::Value>,
+}
+
+impl<P: Problem> Search<'_, P> {
+    /// Whether `state` may still lead to a better value than the best so far.
+    fn is_promising(&self, state: &P::State) -> bool {
+        self.best
+            .is_none_or(|best| P::GOAL.is_better(self.problem.bound(state), best))
+    }
+
+    /// Remembers `state`, returning whether it was neither seen nor dominated before.
+    fn admit(&mut self, state: &P::State) -> bool {
+        if let Visited::Bounded(limit) = self.visited {
+            if self.seen.len() >= limit {
+                self.seen.clear();
+            }
+            if self.scores.len() >= limit {
+                self.scores.clear();
+            }
+        }
+
+        if let Some((key, score)) = self.problem.dominance(state) {
+            match self.scores.get_mut(&key) {
+                Some(best) if !P::GOAL.is_better(score, *best) => return false,
+                Some(best) => *best = score,
+                None => {
+                    self.scores.insert(key, score);
+                }
+            }
+        }
+        self.visited == Visited::Off || self.seen.insert(state.clone())
+    }
+}
+
+/// The best value of any state that follows from `starts`, or `None` if there is no solution.
+pub fn solve<P: Problem>(
+    problem: &P,
+    starts: impl IntoIterator<Item = P::State>,
+    visited: Visited,
+) -> Option<P::Value> {
+    let mut search = Search {
+        problem,
+        visited,
+        best: None,
+        seen: HashSet::new(),
+        scores: HashMap::new(),
+    };
+    let mut stack: Vec<P::State> = starts
+        .into_iter()
+        .filter(|state| search.admit(state))
+        .collect();
+
+    while let Some(state) = stack.pop() {
+        // the best value may have improved since the state was pushed.
+        if !search.is_promising(&state) {
+            continue;
+        }
+        if let Some(value) = problem.value(&state) {
+            if search
+                .best
+                .is_none_or(|best| P::GOAL.is_better(value, best))
+            {
+                search.best = Some(value);
+            }
+        }
+
+        for next in problem.branch(&state) {
+            if search.is_promising(&next) && search.admit(&next) {
+                stack.push(next);
+            }
+        }
+    }
+
+    search.best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Picks items of the given weights and values, without going over the capacity.
+    struct Knapsack {
+        items: Vec<(u32, u32)>,
+        capacity: u32,
+    }
+
+    /// The next item to decide on, the weight and the value so far.
+    type Packed = (usize, u32, u32);
+
+    impl Problem for Knapsack {
+        type State = Packed;
+        type Value = u32;
+        type Key = ();
+
+        const GOAL: Goal = Goal::Maximise;
+
+        fn branch(&self, &(index, weight, value): &Packed) -> Vec<Packed> {
+            let Some(&(item_weight, item_value)) = self.items.get(index) else {
+                return vec![];
+            };
+            let mut next = vec![(index + 1, weight, value)];
+            if weight + item_weight <= self.capacity {
+                next.push((index + 1, weight + item_weight, value + item_value));
+            }
+            next
+        }
+
+        fn bound(&self, &(index, _, value): &Packed) -> u32 {
+            value + self.items[index..].iter().map(|&(_, v)| v).sum::<u32>()
+        }
+
+        fn value(&self, &(_, _, value): &Packed) -> Option<u32> {
+            Some(value)
+        }
+    }
+
+    /// Pays an amount with as few coins as possible.
+    struct Change {
+        coins: Vec<u32>,
+    }
+
+    impl Problem for Change {
+        /// The amount left to pay and the coins used so far.
+        type State = (u32, u32);
+        type Value = u32;
+        /// The amount left to pay.
+        type Key = u32;
+
+        const GOAL: Goal = Goal::Minimise;
+
+        fn branch(&self, &(amount, count): &(u32, u32)) -> Vec<(u32, u32)> {
+            self.coins
+                .iter()
+                .filter(|&&coin| coin <= amount)
+                .map(|coin| (amount - coin, count + 1))
+                .collect()
+        }
+
+        fn bound(&self, &(amount, count): &(u32, u32)) -> u32 {
+            count + amount.div_ceil(*self.coins.iter().max().unwrap())
+        }
+
+        fn value(&self, &(amount, count): &(u32, u32)) -> Option<u32> {
+            (amount == 0).then_some(count)
+        }
+
+        fn dominance(&self, &(amount, count): &(u32, u32)) -> Option<(u32, u32)> {
+            Some((amount, count))
+        }
+    }
+
+    #[test]
+    fn test_maximise() {
+        let knapsack = Knapsack {
+            items: vec![(5, 10), (4, 40), (6, 30), (3, 50)],
+            capacity: 10,
+        };
+        assert_eq!(solve(&knapsack, [(0, 0, 0)], Visited::Unbounded), Some(90));
+        assert_eq!(solve(&knapsack, [(0, 0, 0)], Visited::Bounded(2)), Some(90));
+    }
+
+    #[test]
+    fn test_minimise() {
+        let change = Change {
+            coins: vec![1, 5, 6, 9],
+        };
+        assert_eq!(solve(&change, [(11, 0)], Visited::Off), Some(2));
+        assert_eq!(solve(&change, [(11, 0)], Visited::Bounded(1)), Some(2));
+        assert_eq!(
+            solve(&Change { coins: vec![2] }, [(3, 0)], Visited::Off),
+            None
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! Finds where a simulation starts repeating itself, to skip ahead to a step that is too far away
+//! to simulate.
+//!
+//! Both [`find`] and [`brent`] compare states by a key, which may leave out anything that does not
+//! affect the future, such as a counter that only grows.
+
+use std::hash::Hash;
+
+use hashbrown::hash_map::Entry;
+use hashbrown::HashMap;
+use num::PrimInt;
+
+/// The state after `start` steps is the first one that recurs, every `len` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+/// Advances `state` with `step` until its key repeats, remembering the key of every step.
+///
+/// Leaves `state` at step `start + len`, so that a metric recorded by `step` after every step is
+/// known up to there, as [`extrapolate`] needs it. Never returns if no key repeats.
+pub fn find<S, K>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle
+where
+    K: Hash + Eq,
+{
+    let mut seen: HashMap<K, usize> = HashMap::new();
+    for steps in 0.. {
+        match seen.entry(key(state)) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                return Cycle {
+                    start,
+                    len: steps - start,
+                };
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(steps);
+            }
+        }
+        step(state);
+    }
+    unreachable!()
+}
+
+/// Like [`find`], but keeps only a few states instead of every key, using Brent's algorithm. This
+/// takes more steps and clones of the state, and leaves `initial` untouched.
+pub fn brent<S, K>(initial: &S, mut step: impl FnMut(&mut S), mut key: impl FnMut(&S) -> K) -> Cycle
+where
+    S: Clone,
+    K: Eq,
+{
+    // find the length by moving the hare ahead until it meets the tortoise, which jumps to the
+    // hare at every power of two.
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    step(&mut hare);
+    while key(&tortoise) != key(&hare) {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        step(&mut hare);
+        len += 1;
+    }
+
+    // find the start by moving both from the beginning, `len` steps apart.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..len {
+        step(&mut hare);
+    }
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    Cycle { start, len }
+}
+
+/// The value of a metric after `steps` steps, given its `history` from step 0 up to at least step
+/// `cycle.start + cycle.len`. The metric must change by the same amount in every cycle, e.g. a
+/// height that grows or a count that adds up.
+///
+/// ```
+/// use advent_of_code::helpers::cycle::{extrapolate, Cycle};
+///
+/// // 3 steps of 1 each, then a cycle of 2 steps that adds 10.
+/// let history = [0, 1, 2, 3, 8, 13];
+/// let cycle = Cycle { start: 3, len: 2 };
+/// assert_eq!(extrapolate(cycle, &history, 4), 8);
+/// assert_eq!(extrapolate(cycle, &history, 8), 28);
+/// assert_eq!(extrapolate(cycle, &history, 1_000_001), 4_999_993);
+/// ```
+pub fn extrapolate<T: PrimInt>(cycle: Cycle, history: &[T], steps: usize) -> T {
+    if steps < history.len() {
+        return history[steps];
+    }
+    let Cycle { start, len } = cycle;
+    let per_cycle = history[start + len] - history[start];
+    let cycles = T::from((steps - start) / len).expect("too many cycles for the metric type");
+    history[start + (steps - start) % len] + cycles * per_cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts up from 0 to 6, then goes back to 3.
+    fn next(n: &mut u32) {
+        *n = if *n == 6 { 3 } else { *n + 1 };
+    }
+
+    #[test]
+    fn test_find() {
+        let mut state = 0;
+        let cycle = find(&mut state, next, |&n| n);
+        assert_eq!(cycle, Cycle { start: 3, len: 4 });
+        assert_eq!(state, 3);
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(&0, next, |&n| n), Cycle { start: 3, len: 4 });
+        assert_eq!(brent(&3, next, |&n| n), Cycle { start: 0, len: 4 });
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // the sum of the sequence after every step.
+        let (mut state, mut sum) = (0, 0);
+        let mut history = vec![0];
+        let cycle = find(
+            &mut state,
+            |n| {
+                next(n);
+                sum += *n as u64;
+                history.push(sum);
+            },
+            |&n| n,
+        );
+
+        let mut expected = (0, 0);
+        for steps in 1..=100 {
+            next(&mut expected.0);
+            expected.1 += expected.0 as u64;
+            assert_eq!(extrapolate(cycle, &history, steps), expected.1);
+        }
+    }
+}
@@ -0,0 +1,420 @@
+//! A rectangular grid of cells, for puzzles that come as a character map.
+//!
+//! Positions are [`Point2`]s, with `x` growing to the right and `y` growing downwards, so
+//! `grid[Point2::new(x, y)]` is the cell of the `x`th character on line `y` of the input. Steps
+//! between cells are `Point2<isize>` vectors, such as [`Direction::vector`].
+
+use std::fmt::{self, Display};
+use std::iter;
+use std::ops::{Index, IndexMut};
+
+use crate::error::SolveError;
+use crate::helpers::point::{Direction, Point2};
+
+/// The position of a cell.
+pub type Pos = Point2<usize>;
+
+/// A step from one cell to another.
+pub type Vector = Point2<isize>;
+
+/// A `width` by `height` grid, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows.
+    ///
+    /// Panics if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "all rows must have the same length"
+        );
+        Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses a character map with one row per line, turning every character into a cell with
+    /// `cell`. Fails on characters that `cell` rejects and on lines of different lengths.
+    ///
+    /// ```
+    /// use advent_of_code::helpers::grid::Grid;
+    /// use advent_of_code::helpers::point::Point2;
+    ///
+    /// let grid = Grid::parse("123\n456\n", |c| c.to_digit(10)).unwrap();
+    /// assert_eq!(grid[Point2::new(2, 1)], 6);
+    /// ```
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, SolveError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let row_start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    SolveError::Invalid(format!(
+                        "unexpected {:?} at line {}, column {}",
+                        c,
+                        y + 1,
+                        x + 1
+                    ))
+                })?;
+                cells.push(value);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(SolveError::Invalid(format!(
+                        "line {} has {} cells, expected {}",
+                        y + 1,
+                        row_width,
+                        width
+                    )));
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, Point2 { x, y }: Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.offset(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.contains(pos) {
+            return None;
+        }
+        let offset = self.offset(pos);
+        Some(&mut self.cells[offset])
+    }
+
+    fn offset(&self, Point2 { x, y }: Pos) -> usize {
+        y * self.width + x
+    }
+
+    fn pos(&self, offset: usize) -> Pos {
+        Point2::new(offset % self.width, offset / self.width)
+    }
+
+    /// The position one `step` away from `pos`, if it is inside the grid.
+    pub fn step(&self, pos: Pos, step: Vector) -> Option<Pos> {
+        let pos = Point2::new(
+            pos.x.checked_add_signed(step.x)?,
+            pos.y.checked_add_signed(step.y)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// The orthogonal neighbours of `pos` inside the grid, clockwise from up.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction.vector()))
+    }
+
+    /// All neighbours of `pos` inside the grid, including diagonal ones, clockwise from up.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .flat_map(|direction| {
+                let diagonal = direction.vector() + direction.turn_right().vector();
+                [direction.vector(), diagonal]
+            })
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    /// The positions from `pos` in steps of `step` up to the edge of the grid, not including
+    /// `pos` itself.
+    pub fn ray(&self, pos: Pos, step: Vector) -> impl Iterator<Item = Pos> + '_ {
+        iter::successors(self.step(pos, step), move |&pos| self.step(pos, step))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + '_ {
+        (0..self.cells.len()).map(|offset| self.pos(offset))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|offset| self.pos(offset))
+    }
+
+    /// Panics if `y` is out of range.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {} is outside of the grid", y);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Panics if `x` is out of range.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The diagonals that run down and to the right, from the bottom left to the top right
+    /// corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| Point2::new(0, y))
+            .chain((1..self.width).map(|x| Point2::new(x, 0)));
+        self.lines_from(starts, Point2::new(1, 1))
+    }
+
+    /// The diagonals that run down and to the left, from the top left to the bottom right
+    /// corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .map(|x| Point2::new(x, 0))
+            .chain((1..self.height).map(|y| Point2::new(self.width.saturating_sub(1), y)));
+        self.lines_from(starts, Point2::new(-1, 1))
+    }
+
+    fn lines_from<'a>(
+        &'a self,
+        starts: impl Iterator<Item = Pos> + 'a,
+        step: Vector,
+    ) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        starts
+            .filter(|&start| self.contains(start))
+            .map(move |start| {
+                iter::once(start)
+                    .chain(self.ray(start, step))
+                    .map(|pos| &self[pos])
+            })
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Turns the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| (0..self.height).rev().map(move |y| Point2::new(x, y)))
+                .map(|pos| self[pos].clone())
+                .collect(),
+        }
+    }
+
+    /// Turns the grid a quarter turn counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|x| self.column(x))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    /// Panics if `pos` is outside of the grid, use [`Grid::get`] to check.
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "{:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside of the {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+/// Renders the grid as a character map, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", Some).unwrap()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point2::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert_eq!(
+            Grid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]),
+            grid
+        );
+
+        assert_eq!(
+            Grid::parse("12\n3x\n", |c| c.to_digit(10)),
+            Err(SolveError::Invalid(
+                "unexpected 'x' at line 2, column 2".to_string()
+            ))
+        );
+        assert_eq!(
+            Grid::parse("12\n3\n", Some),
+            Err(SolveError::Invalid(
+                "line 2 has 1 cells, expected 2".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        let points = |points: &[(usize, usize)]| -> Vec<Pos> {
+            points.iter().copied().map(Point2::from).collect()
+        };
+        assert_eq!(
+            grid.neighbors4(Point2::new(0, 0)).collect::<Vec<_>>(),
+            points(&[(1, 0), (0, 1)])
+        );
+        assert_eq!(
+            grid.neighbors8(Point2::new(1, 1)).collect::<Vec<_>>(),
+            points(&[(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)])
+        );
+        assert_eq!(
+            grid.ray(Point2::new(0, 1), Direction::Right.vector())
+                .collect::<Vec<_>>(),
+            points(&[(1, 1), (2, 1)])
+        );
+        assert_eq!(
+            grid.ray(Point2::new(0, 1), Direction::Left.vector())
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(collect(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(collect(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Point2::new(1, 1)));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).to_string(),
+            "ABC\nDEF\n"
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! Points in two and three dimensions, which double as the vectors between them, and the four
+//! directions on a map.
+//!
+//! [`Grid`](super::grid::Grid) positions are `Point2<usize>`, so like there `x` grows to the right
+//! and `y` grows downwards, and [`Direction::Up`] is a step towards smaller `y`.
+
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num::Signed;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// `|a - b|`, for signed and unsigned types alike.
+fn abs_diff<T: Copy + Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    match a.cmp(&b) {
+        Ordering::Less => b - a,
+        _ => a - b,
+    }
+}
+
+/// Implements the operators for a point type with the given fields, all of them component-wise
+/// except for multiplying with a scalar.
+macro_rules! implement_ops {
+    ($point:ident, $($field:ident),+) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, scalar: T) -> Self {
+                $point { $($field: self.$field * scalar),+ }
+            }
+        }
+
+        impl<T> $point<T> {
+            /// Applies `f` to every coordinate, e.g. to convert them to another type.
+            pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> $point<U> {
+                $point { $($field: f(self.$field)),+ }
+            }
+        }
+
+        impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> $point<T> {
+            /// The number of orthogonal steps between the points.
+            pub fn manhattan(self, other: Self) -> T {
+                let mut diffs = [$(abs_diff(self.$field, other.$field)),+].into_iter();
+                let first = diffs.next().unwrap();
+                diffs.fold(first, |sum, diff| sum + diff)
+            }
+
+            /// The number of steps between the points when diagonal steps are allowed.
+            pub fn chebyshev(self, other: Self) -> T {
+                [$(abs_diff(self.$field, other.$field)),+]
+                    .into_iter()
+                    .max()
+                    .unwrap()
+            }
+        }
+    };
+}
+
+implement_ops!(Point2, x, y);
+implement_ops!(Point3, x, y, z);
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(point: Point2<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(point: Point3<T>) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Parses a direction from `U`, `R`, `D` or `L`, or from `^`, `>`, `v` or `<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Direction::Up),
+            'R' | '>' => Some(Direction::Right),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step of length one in this direction.
+    ///
+    /// ```
+    /// use advent_of_code::helpers::point::{Direction, Point2};
+    ///
+    /// let point = Point2::new(3, 3) + Direction::Up.vector() * 2;
+    /// assert_eq!(point, Point2::new(3, 1));
+    /// ```
+    pub fn vector<T: Signed>(self) -> Point2<T> {
+        match self {
+            Direction::Up => Point2::new(T::zero(), -T::one()),
+            Direction::Right => Point2::new(T::one(), T::zero()),
+            Direction::Down => Point2::new(T::zero(), T::one()),
+            Direction::Left => Point2::new(-T::one(), T::zero()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let mut point = Point2::new(1, 2) + Point2::new(3, -4) * 2;
+        assert_eq!(point, Point2::new(7, -6));
+        point -= Point2::new(7, 0);
+        assert_eq!(-point, Point2::new(0, 6));
+        assert_eq!(Point2::new(-3, 5).map(i32::signum), Point2::new(-1, 1));
+
+        let point = Point3::new(1, 2, 3) - Point3::new(1, 1, 1);
+        assert_eq!(<(i32, i32, i32)>::from(point), (0, 1, 2));
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point2::new(1, 5), Point2::new(4, 1));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+
+        let (a, b) = (Point3::new(1usize, 5, 2), Point3::new(4, 1, 2));
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(b.chebyshev(a), 4);
+    }
+
+    #[test]
+    fn test_direction() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(
+                direction.vector::<i32>() + direction.opposite().vector(),
+                Point2::default()
+            );
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::from_char('<'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('x'), None);
+    }
+}
@@ -0,0 +1,244 @@
+//! Shortest path searches over any state type, given a function that lists its successors.
+//!
+//! All searches take several start states, stop at the first state that satisfies `is_goal` and
+//! return `None` if no goal is reachable. Ties between equally cheap paths are broken arbitrarily.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use hashbrown::hash_map::Entry;
+use hashbrown::HashMap;
+
+struct Node<S, C> {
+    state: S,
+    cost: C,
+    parent: Option<usize>,
+}
+
+/// A goal reached by a search, along with everything needed to reconstruct how.
+pub struct Found<S, C> {
+    nodes: Vec<Node<S, C>>,
+    goal: usize,
+}
+
+impl<S, C: Copy> Found<S, C> {
+    pub fn goal(&self) -> &S {
+        &self.nodes[self.goal].state
+    }
+
+    /// The total cost of the cheapest path to the goal.
+    pub fn cost(&self) -> C {
+        self.nodes[self.goal].cost
+    }
+
+    /// The states on the cheapest path, from one of the starts to the goal.
+    pub fn path(&self) -> Vec<&S> {
+        let mut path: Vec<&S> =
+            std::iter::successors(Some(self.goal), |&index| self.nodes[index].parent)
+                .map(|index| &self.nodes[index].state)
+                .collect();
+        path.reverse();
+        path
+    }
+}
+
+/// Finds the cheapest path from any of `starts` to a goal, where `successors` lists the states
+/// reachable from a state along with the cost of getting there.
+///
+/// ```
+/// use advent_of_code::helpers::search::dijkstra;
+///
+/// // the cheapest way to count from 0 to 9 by adding 1 for a cost of 1, or 3 for a cost of 2.
+/// let found = dijkstra([0], |&n| [(n + 1, 1), (n + 3, 2)], |&n| n == 9).unwrap();
+/// assert_eq!(found.cost(), 6);
+/// assert_eq!(found.path(), [&0, &3, &6, &9]);
+/// ```
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but explores the states first that `heuristic` estimates to be closest to a
+/// goal. The result is only the cheapest path if `heuristic` never overestimates the remaining
+/// cost.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut nodes: Vec<Node<S, C>> = vec![];
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    // ordered by estimated total cost, then by the cost so far.
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = indices.entry(start.clone()) {
+            entry.insert(nodes.len());
+            heap.push(Reverse((heuristic(&start), C::default(), nodes.len())));
+            nodes.push(Node {
+                state: start,
+                cost: C::default(),
+                parent: None,
+            });
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        // a cheaper path to this state was found after the entry was pushed.
+        if cost > nodes[index].cost {
+            continue;
+        }
+        if is_goal(&nodes[index].state) {
+            return Some(Found { nodes, goal: index });
+        }
+
+        for (next, step_cost) in successors(&nodes[index].state) {
+            let next_cost = cost + step_cost;
+            let next_index = match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let node = &mut nodes[*entry.get()];
+                    if next_cost >= node.cost {
+                        continue;
+                    }
+                    node.cost = next_cost;
+                    node.parent = Some(index);
+                    *entry.get()
+                }
+                Entry::Vacant(entry) => {
+                    let state = entry.key().clone();
+                    entry.insert(nodes.len());
+                    nodes.push(Node {
+                        state,
+                        cost: next_cost,
+                        parent: Some(index),
+                    });
+                    nodes.len() - 1
+                }
+            };
+            let estimate = next_cost + heuristic(&nodes[next_index].state);
+            heap.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+/// Finds the path with the fewest steps from any of `starts` to a goal, where `successors` lists
+/// the states reachable in one step. The cost of the result is the number of steps.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Found<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut nodes: Vec<Node<S, usize>> = vec![];
+    let mut indices: HashMap<S, usize> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = indices.entry(start.clone()) {
+            entry.insert(nodes.len());
+            queue.push_back(nodes.len());
+            nodes.push(Node {
+                state: start,
+                cost: 0,
+                parent: None,
+            });
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&nodes[index].state) {
+            return Some(Found { nodes, goal: index });
+        }
+
+        let cost = nodes[index].cost + 1;
+        for next in successors(&nodes[index].state) {
+            if let Entry::Vacant(entry) = indices.entry(next) {
+                let state = entry.key().clone();
+                entry.insert(nodes.len());
+                queue.push_back(nodes.len());
+                nodes.push(Node {
+                    state,
+                    cost,
+                    parent: Some(index),
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A weighted graph where the direct edge 0 -> 3 is more expensive than the detour.
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let found = dijkstra([0], edges, |&node| node == 3).unwrap();
+        assert_eq!(found.cost(), 6);
+        assert_eq!(found.path(), [&0, &1, &2, &3]);
+
+        let found = dijkstra([0, 2], edges, |&node| node == 3).unwrap();
+        assert_eq!(found.cost(), 3);
+        assert_eq!(found.path(), [&2, &3]);
+
+        assert!(dijkstra([1], edges, |&node| node == 0).is_none());
+    }
+
+    #[test]
+    fn test_astar() {
+        // a 5x5 open grid, with the goal in the opposite corner.
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y))
+                .map(|pos| (pos, 1))
+        };
+        let distance = |&(x, y): &(i32, i32)| (4 - x) + (4 - y);
+
+        let found = astar([(0, 0)], successors, distance, |&pos| pos == (4, 4)).unwrap();
+        assert_eq!(found.cost(), 8);
+        assert_eq!(found.path().len(), 9);
+        assert_eq!(found.goal(), &(4, 4));
+    }
+
+    #[test]
+    fn test_bfs() {
+        let found = bfs([1], |&n| [n * 2, n + 1], |&n| n == 10).unwrap();
+        assert_eq!(found.cost(), 4);
+        assert_eq!(found.path(), [&1, &2, &4, &5, &10]);
+
+        assert!(bfs([1], |&n: &u32| (n < 5).then_some(n + 1), |&n| n == 10).is_none());
+    }
+}
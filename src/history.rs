@@ -0,0 +1,359 @@
+use std::cmp::Reverse;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::report::{Record, Step};
+
+pub const DEFAULT_PATH: &str = "history.csv";
+
+/// How many of the latest runs on the same host make up the baseline of a step.
+pub const BASELINE_RUNS: usize = 5;
+
+const HEADER: &str = "timestamp_ms,commit,host,year,day,step,part,elapsed_ns,median_ns";
+
+/// A recorded timing of one step of a day, as stored in the history file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// Milliseconds since the UNIX epoch. All entries of a run share the same timestamp.
+    pub timestamp_ms: u64,
+    pub commit: String,
+    pub host: String,
+    pub year: u16,
+    pub day: u8,
+    pub step: Step,
+    pub elapsed: Duration,
+    /// Only present for runs in benchmark mode.
+    pub median: Option<Duration>,
+}
+
+impl Entry {
+    /// The timing used for comparisons: the benchmark median if available, else the single run.
+    pub fn time(&self) -> Duration {
+        self.median.unwrap_or(self.elapsed)
+    }
+
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{}",
+            self.timestamp_ms,
+            self.commit,
+            self.host,
+            self.year,
+            self.day,
+            self.step.kind(),
+            self.step
+                .part()
+                .map_or(String::new(), |part| part.to_string()),
+            self.elapsed.as_nanos(),
+            self.median
+                .map_or(String::new(), |median| median.as_nanos().to_string()),
+        )
+    }
+
+    fn from_csv(line: &str) -> Option<Self> {
+        let mut fields = line.split(',');
+        let mut next = || fields.next();
+        let nanos = |value: &str| value.parse().ok().map(Duration::from_nanos);
+        Some(Entry {
+            timestamp_ms: next()?.parse().ok()?,
+            commit: next()?.to_string(),
+            host: next()?.to_string(),
+            year: next()?.parse().ok()?,
+            day: next()?.parse().ok()?,
+            step: Step::from_fields(next()?, next()?)?,
+            elapsed: nanos(next()?)?,
+            median: match next()? {
+                "" => None,
+                value => Some(nanos(value)?),
+            },
+        })
+    }
+}
+
+/// Identifies the machine and code a run was made with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunInfo {
+    pub timestamp_ms: u64,
+    pub commit: String,
+    pub host: String,
+}
+
+impl RunInfo {
+    pub fn current() -> Self {
+        RunInfo {
+            timestamp_ms: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_millis() as u64),
+            commit: git_commit().unwrap_or_else(|| "unknown".to_string()),
+            host: sanitize(&format!(
+                "{} ({}-{})",
+                hostname().unwrap_or_else(|| "unknown".to_string()),
+                env::consts::OS,
+                env::consts::ARCH
+            )),
+        }
+    }
+
+    /// Turns the solved parts and successful parse steps among `records` into history entries.
+    ///
+    /// Contended timings are skipped, as they would make for misleading baselines.
+    pub fn entries(&self, records: &[Record]) -> Vec<Entry> {
+        records
+            .iter()
+            .filter(|record| record.result.answer().is_some() && !record.contended)
+            .map(|record| Entry {
+                timestamp_ms: self.timestamp_ms,
+                commit: self.commit.clone(),
+                host: self.host.clone(),
+                year: record.year,
+                day: record.day,
+                step: record.step,
+                elapsed: record.result.elapsed,
+                median: record.stats.map(|stats| stats.median),
+            })
+            .collect()
+    }
+}
+
+/// The short hash of `HEAD`, suffixed with `-dirty` if the working tree has changes.
+fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+
+    let dirty = Command::new("git")
+        .args(["status", "--porcelain"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty());
+
+    Some(if dirty {
+        format!("{}-dirty", commit)
+    } else {
+        commit
+    })
+}
+
+fn hostname() -> Option<String> {
+    env::var("HOSTNAME")
+        .or_else(|_| env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+/// Keeps free-form values from breaking the CSV layout.
+fn sanitize(value: &str) -> String {
+    value.replace([',', '\n', '\r'], " ")
+}
+
+/// Reads all entries from the history file at `path`. A missing file is an empty history.
+pub fn load(path: &Path) -> io::Result<Vec<Entry>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => {
+            check_header(path, &contents)?;
+            Ok(contents
+                .lines()
+                .filter(|line| *line != HEADER)
+                .filter_map(Entry::from_csv)
+                .collect())
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+/// Refuses history files with other columns, e.g. from before entries had a year.
+fn check_header(path: &Path, contents: &str) -> io::Result<()> {
+    match contents.lines().next() {
+        None => Ok(()),
+        Some(HEADER) => Ok(()),
+        Some(_) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{} has an outdated format, move it away to start a new history",
+                path.display()
+            ),
+        )),
+    }
+}
+
+/// Appends `entries` to the history file at `path`, creating it if needed.
+pub fn append(path: &Path, entries: &[Entry]) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .create(true)
+        .append(true)
+        .open(path)?;
+    let mut header = String::new();
+    BufReader::new(&file).read_line(&mut header)?;
+    check_header(path, &header)?;
+    if header.is_empty() {
+        writeln!(file, "{}", HEADER)?;
+    }
+    for entry in entries {
+        writeln!(file, "{}", entry.to_csv())?;
+    }
+    Ok(())
+}
+
+/// A step that got slower than its baseline by more than the threshold.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Regression {
+    pub day: u8,
+    pub step: Step,
+    pub baseline: Entry,
+    pub current: Duration,
+}
+
+impl Regression {
+    /// The slowdown relative to the baseline, or `None` if the baseline took no measurable time.
+    pub fn percent(&self) -> Option<f64> {
+        let baseline = self.baseline.time().as_secs_f64();
+        (baseline > 0_f64).then(|| (self.current.as_secs_f64() - baseline) / baseline * 100_f64)
+    }
+}
+
+/// The entry with the median time among the latest [`BASELINE_RUNS`] entries in `history` with
+/// the same host, year, day and step as `entry`.
+///
+/// A single slow or fast run does not move the median, and a series of small slowdowns only
+/// moves it after several runs, so they still add up to a regression.
+fn baseline<'a>(history: &'a [Entry], entry: &Entry) -> Option<&'a Entry> {
+    let mut runs: Vec<&Entry> = history
+        .iter()
+        .filter(|old| {
+            old.host == entry.host
+                && old.year == entry.year
+                && old.day == entry.day
+                && old.step == entry.step
+        })
+        .collect();
+    runs.sort_by_key(|old| Reverse(old.timestamp_ms));
+    runs.truncate(BASELINE_RUNS);
+    runs.sort_by_key(|old| old.time());
+    runs.get(runs.len() / 2).copied()
+}
+
+/// Compares `current` against the baseline of every step in `history`, see [`baseline`].
+///
+/// Timings from other hosts are never used as a baseline, as they are not comparable.
+pub fn find_regressions(history: &[Entry], current: &[Entry], threshold: f64) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|entry| {
+            let baseline = baseline(history, entry)?;
+
+            let limit = baseline.time().as_secs_f64() * (1_f64 + threshold / 100_f64);
+            (entry.time().as_secs_f64() > limit).then(|| Regression {
+                day: entry.day,
+                step: entry.step,
+                baseline: baseline.clone(),
+                current: entry.time(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp_ms: u64, host: &str, step: Step, elapsed_us: u64) -> Entry {
+        Entry {
+            timestamp_ms,
+            commit: "abc1234".to_string(),
+            host: host.to_string(),
+            year: 2022,
+            day: 16,
+            step,
+            elapsed: Duration::from_micros(elapsed_us),
+            median: None,
+        }
+    }
+
+    #[test]
+    fn test_csv_roundtrip() {
+        let mut benched = entry(1, "box (linux-x86_64)", Step::Part(1), 100);
+        benched.median = Some(Duration::from_micros(90));
+
+        for entry in [
+            entry(1, "box (linux-x86_64)", Step::Part(2), 100),
+            entry(1, "box (linux-x86_64)", Step::Parse, 100),
+            benched,
+        ] {
+            assert_eq!(Entry::from_csv(&entry.to_csv()), Some(entry));
+        }
+        assert_eq!(Entry::from_csv(HEADER), None);
+    }
+
+    #[test]
+    fn test_check_header() {
+        let path = Path::new("history.csv");
+        assert!(check_header(path, "").is_ok());
+        assert!(check_header(path, &format!("{}\n1,abc,box,2022,1,part,1,100,\n", HEADER)).is_ok());
+        assert!(check_header(
+            path,
+            "timestamp_ms,commit,host,year,day,part,elapsed_ns,median_ns\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_find_regressions() {
+        let history = [
+            entry(1, "a", Step::Part(1), 200),
+            entry(2, "a", Step::Part(1), 100),
+            entry(2, "a", Step::Part(2), 100),
+            entry(3, "a", Step::Part(1), 200),
+            entry(3, "b", Step::Part(1), 10),
+            entry(3, "a", Step::Parse, 0),
+        ];
+        let current = [
+            entry(4, "a", Step::Part(1), 215),
+            entry(4, "a", Step::Part(2), 120),
+            entry(4, "a", Step::Parse, 5),
+        ];
+
+        let regressions = find_regressions(&history, &current, 10_f64);
+        assert_eq!(regressions.len(), 2);
+        assert_eq!(regressions[0].step, Step::Part(2));
+        assert_eq!(regressions[1].step, Step::Parse);
+        assert_eq!(regressions[0].baseline, history[2]);
+        assert!((regressions[0].percent().unwrap() - 20_f64).abs() < 1e-6);
+        // a baseline of zero has no meaningful percentage.
+        assert_eq!(regressions[1].percent(), None);
+    }
+
+    #[test]
+    fn test_baseline() {
+        // one slow run does not become the baseline.
+        let history = [100, 100, 300, 100].map(|us| entry(us, "a", Step::Part(1), us));
+        assert_eq!(
+            baseline(&history, &history[0]).unwrap().time().as_micros(),
+            100
+        );
+
+        // slowdowns of 5% each add up to more than 10% after a few runs.
+        let mut history = vec![entry(0, "a", Step::Part(1), 1000)];
+        let mut elapsed_us = 1000;
+        let flagged = (1..10).find(|&run| {
+            elapsed_us = elapsed_us * 105 / 100;
+            let current = entry(run, "a", Step::Part(1), elapsed_us);
+            let regressed =
+                !find_regressions(&history, std::slice::from_ref(&current), 10_f64).is_empty();
+            history.push(current);
+            regressed
+        });
+        assert_eq!(flagged, Some(3));
+    }
+}
@@ -2,45 +2,363 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers::Answers;
+use advent_of_code::history::{self, Regression, RunInfo};
+use advent_of_code::readme;
+use advent_of_code::report::{Format, Record, Reporter};
+use advent_of_code::runner::{solve_day, solve_day_in_child, Options};
+use advent_of_code::years;
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+struct Args {
+    year: u16,
+    options: Options,
+    history: PathBuf,
+    record_history: bool,
+    compare: bool,
+    threshold: f64,
+    jobs: usize,
+    /// Only run these days, all if `None`.
+    days: Option<Vec<u8>>,
+    timeout: Option<Duration>,
+    /// Write the table of results into the README.
+    readme: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(years::latest),
+        options: Options::from_args(&mut args)?,
+        history: args
+            .opt_value_from_str("--history")?
+            .unwrap_or_else(|| PathBuf::from(history::DEFAULT_PATH)),
+        record_history: !args.contains("--no-history"),
+        compare: args.contains("--compare"),
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+        jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+        days: args.opt_value_from_fn("--days", parse_days)?,
+        timeout: args
+            .opt_value_from_str("--timeout")?
+            .map(Duration::from_secs_f64),
+        readme: args.contains("--readme"),
+    })
+}
+
+/// Parses a list of days and ranges of days, e.g. `1-10,15`.
+fn parse_days(s: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day \"{}\", expected 1 to 25", day)),
+    };
+
+    let mut days = vec![];
+    for item in s.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                if first > last {
+                    return Err(format!("invalid range \"{}\"", item));
                 }
-            );
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+/// Calls `run` for every index in `0..count` on `jobs` threads, and passes everything it emits
+/// to `report` with its index, in index order. With a single job that happens right away,
+/// otherwise as soon as the runs of all previous indices are done.
+fn run_in_order<T: Send>(
+    count: usize,
+    jobs: usize,
+    run: impl Fn(usize, &mut dyn FnMut(T)) + Sync,
+    mut report: impl FnMut(usize, T),
+) {
+    if jobs <= 1 {
+        (0..count).for_each(|index| run(index, &mut |item| report(index, item)));
+        return;
+    }
+
+    let next_index = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(count) {
+            let sender = sender.clone();
+            let (next_index, run) = (&next_index, &run);
+            scope.spawn(move || loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+                if index >= count {
+                    break;
+                }
+                let mut items = vec![];
+                run(index, &mut |item| items.push(item));
+                sender.send((index, items)).unwrap();
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut next_to_report = 0;
+        for (index, items) in receiver {
+            pending.insert(index, items);
+            while let Some(items) = pending.remove(&next_to_report) {
+                for item in items {
+                    report(next_to_report, item);
+                }
+                next_to_report += 1;
             }
+        }
+    });
+}
+
+fn print_regressions(regressions: &[Regression], threshold: f64, format: Format) {
+    let (bold, reset) = match format {
+        Format::Text => (ANSI_BOLD, ANSI_RESET),
+        Format::Json | Format::Csv => ("", ""),
+    };
+    let mut lines = vec![if regressions.is_empty() {
+        format!(
+            "{}No regressions{} (threshold: {}%).",
+            bold, reset, threshold
+        )
+    } else {
+        format!(
+            "{}Regressions{} (more than {}% slower than recent runs on this host):",
+            bold, reset, threshold
+        )
+    }];
+    lines.extend(regressions.iter().map(|regression| {
+        let slowdown = match regression.percent() {
+            Some(percent) => format!("+{:.1}%", percent),
+            None => format!(
+                "+{:.2?}",
+                regression
+                    .current
+                    .saturating_sub(regression.baseline.time())
+            ),
+        };
+        format!(
+            "Day {:02} {}: {:.2?} -> {:.2?} ({}, baseline: {})",
+            regression.day,
+            regression.step,
+            regression.baseline.time(),
+            regression.current,
+            slowdown,
+            regression.baseline.commit
+        )
+    }));
+
+    for line in lines {
+        match format {
+            Format::Text => println!("{}", line),
+            Format::Json | Format::Csv => eprintln!("{}", line),
+        }
+    }
+}
+
+/// Runs only `day` of `year` with `options` in `exe`, this binary, and prints its records as CSV.
+fn day_command(exe: &Path, year: u16, day: u8, options: &Options) -> Command {
+    let mut command = Command::new(exe);
+    command.args(["--year", &year.to_string(), "--days", &day.to_string()]);
+    command.arg("--no-history");
+    command.args(
+        Options {
+            format: Format::Csv,
+            ..options.clone()
+        }
+        .to_args(),
+    );
+    command
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if args.jobs == 0 {
+        eprintln!("--jobs must be at least 1");
+        process::exit(1);
+    }
+    if args.jobs > 1 && args.options.bench.is_some() {
+        eprintln!("--bench needs the machine to itself and cannot be combined with --jobs");
+        process::exit(1);
+    }
+
+    if args.readme && (args.days.is_some() || args.options.part.is_some()) {
+        eprintln!("--readme lists every day and cannot be combined with --days or --part");
+        process::exit(1);
+    }
+    if args.readme && args.jobs > 1 {
+        eprintln!("--readme needs uncontended timings and cannot be combined with --jobs");
+        process::exit(1);
+    }
+
+    if matches!(args.timeout, Some(timeout) if timeout.is_zero()) {
+        eprintln!("--timeout must be greater than 0");
+        process::exit(1);
+    }
+    // with a timeout, every day runs in a child process of this binary that can be killed.
+    let exe = match args.timeout.map(|_| env::current_exe()).transpose() {
+        Ok(exe) => exe,
+        Err(e) => {
+            eprintln!(
+                "--timeout needs to run this binary again, but could not find it: {}",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let year = match years::find(args.year) {
+        Some(year) => year,
+        None => {
+            eprintln!("No solutions for {} yet", args.year);
+            process::exit(1);
+        }
+    };
+    let solutions: Vec<_> = year
+        .solutions
+        .iter()
+        .copied()
+        .filter(|solution| {
+            args.days
+                .as_ref()
+                .is_none_or(|days| days.contains(&solution.day()))
         })
-        .sum();
+        .collect();
+    if solutions.is_empty() {
+        eprintln!("No solved days match --days");
+        process::exit(1);
+    }
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+    // load all answers upfront, so that a broken answers file fails before the first day runs.
+    let answers = match solutions
+        .iter()
+        .map(|solution| Answers::load(solution.year(), solution.day()))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to load answers: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut reporter = Reporter::new(args.options.format);
+    let mut records: Vec<Record> = vec![];
+
+    run_in_order(
+        solutions.len(),
+        args.jobs,
+        |index, emit| {
+            let solution = solutions[index];
+            let emit = |mut record: Record| {
+                record.contended = args.jobs > 1;
+                emit(record)
+            };
+            match (&exe, args.timeout) {
+                (Some(exe), Some(timeout)) => solve_day_in_child(
+                    day_command(exe, solution.year(), solution.day(), &args.options),
+                    solution,
+                    &answers[index],
+                    &args.options,
+                    timeout,
+                    emit,
+                ),
+                _ => solve_day(solution, &answers[index], &args.options, emit),
+            }
+        },
+        |index, record| {
+            let solution = solutions[index];
+            if records.last().is_none_or(|last| last.day != solution.day()) {
+                reporter.day(solution.day());
+            }
+            reporter.record(&record);
+            records.push(record);
+        },
     );
+
+    let mut passed = reporter.finish(true).passed();
+
+    let entries = RunInfo::current().entries(&records);
+    if args.jobs > 1 && (args.compare || args.record_history) {
+        eprintln!("Timings taken with --jobs are contended and left out of the history.");
+    }
+
+    let mut regressed = false;
+    if args.compare {
+        match history::load(&args.history) {
+            Ok(history) => {
+                let regressions = history::find_regressions(&history, &entries, args.threshold);
+                print_regressions(&regressions, args.threshold, args.options.format);
+                regressed = !regressions.is_empty();
+                passed &= !regressed;
+            }
+            Err(e) => {
+                eprintln!("Failed to read history: {}", e);
+                passed = false;
+            }
+        }
+    }
+
+    // a regressed run would pull the baseline towards itself and hide the regression next time.
+    if regressed && args.record_history {
+        eprintln!("Timings of a run with regressions are left out of the history.");
+    } else if args.record_history {
+        if let Err(e) = history::append(&args.history, &entries) {
+            eprintln!("Failed to write history: {}", e);
+            passed = false;
+        }
+    }
+
+    if args.readme {
+        let table = readme::render_table(year.year, &records);
+        match readme::write(Path::new(readme::DEFAULT_PATH), &table) {
+            Ok(true) => eprintln!("Updated the table in {}.", readme::DEFAULT_PATH),
+            Ok(false) => eprintln!("The table in {} is up to date.", readme::DEFAULT_PATH),
+            Err(e) => {
+                eprintln!("Failed to update the readme: {}", e);
+                passed = false;
+            }
+        }
+    }
+
+    if !passed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("1-3,15,2"), Ok(vec![1, 2, 3, 15]));
+        assert_eq!(parse_days("25"), Ok(vec![25]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("1-").is_err());
+    }
 }
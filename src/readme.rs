@@ -0,0 +1,190 @@
+//! Keeps a table of every day's status and timings in the README, see `cargo readme`.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::answers::Verdict;
+use crate::report::{Record, Step};
+use crate::solution::Outcome;
+
+pub const DEFAULT_PATH: &str = "README.md";
+
+/// The table is written between these lines, which must both be present in the README.
+pub const BEGIN_MARKER: &str = "<!--- benchmarking table --->";
+pub const END_MARKER: &str = "<!--- benchmarking table end --->";
+
+/// Renders a Markdown table with one row per day of `records`, in the order they come in.
+///
+/// Parts show their timing in benchmark mode as the median, and are marked with ⭐ if they
+/// produce their known answer.
+pub fn render_table(year: u16, records: &[Record]) -> String {
+    let mut table = format!(
+        "## Benchmarks {}\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        year
+    );
+
+    let mut total = Duration::ZERO;
+    let mut days: Vec<u8> = records.iter().map(|record| record.day).collect();
+    days.dedup();
+    for day in days {
+        let cell = |part| {
+            records
+                .iter()
+                .find(|record| record.day == day && record.step == Step::Part(part))
+                .map_or("-".to_string(), render_cell)
+        };
+        writeln!(
+            table,
+            "| [Day {}](./src/y{}/day{:02}.rs) | {} | {} |",
+            day,
+            year,
+            day,
+            cell(1),
+            cell(2)
+        )
+        .unwrap();
+    }
+    for record in records {
+        if let Outcome::Solved(_) = record.result.outcome {
+            total += time(record);
+        }
+    }
+
+    write!(
+        table,
+        "\n**Total: {:.2}ms**\n",
+        total.as_secs_f64() * 1000_f64
+    )
+    .unwrap();
+    table
+}
+
+fn time(record: &Record) -> Duration {
+    record
+        .stats
+        .as_ref()
+        .map_or(record.result.elapsed, |stats| stats.median)
+}
+
+fn render_cell(record: &Record) -> String {
+    match record.result.outcome {
+        Outcome::Solved(_) => {
+            let mark = match record.verdict {
+                Verdict::Correct => "⭐ ",
+                Verdict::Incorrect { .. } => "❌ ",
+                Verdict::Unknown => "",
+            };
+            format!("{}`{:.1?}`", mark, time(record))
+        }
+        Outcome::Unsolved => "-".to_string(),
+        Outcome::Error(_) => "error".to_string(),
+        Outcome::TimedOut(_) => "timed out".to_string(),
+    }
+}
+
+/// Replaces whatever is between the markers in `contents` with `table`.
+pub fn update(contents: &str, table: &str) -> Result<String, String> {
+    let missing = |marker| {
+        format!(
+            "could not find the line `{}`, add it and `{}` where the table should go",
+            marker, END_MARKER
+        )
+    };
+    let begin = contents
+        .find(BEGIN_MARKER)
+        .ok_or_else(|| missing(BEGIN_MARKER))?
+        + BEGIN_MARKER.len();
+    let end = contents[begin..]
+        .find(END_MARKER)
+        .ok_or_else(|| missing(END_MARKER))?
+        + begin;
+
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &contents[..begin],
+        table.trim_end(),
+        &contents[end..]
+    ))
+}
+
+/// Writes `table` into the README at `path`. Returns whether the file changed.
+pub fn write(path: &Path, table: &str) -> Result<bool, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let updated = update(&contents, table).map_err(|e| format!("{}: {}", path.display(), e))?;
+    if updated == contents {
+        return Ok(false);
+    }
+    fs::write(path, updated).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Answer;
+    use crate::solution::PartResult;
+
+    use super::*;
+
+    fn record(day: u8, part: u8, outcome: Outcome, verdict: Verdict) -> Record {
+        Record {
+            year: 2022,
+            day,
+            step: Step::Part(part),
+            result: PartResult {
+                outcome,
+                elapsed: Duration::from_micros(1500),
+                alloc: None,
+            },
+            contended: false,
+            stats: None,
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_render_table() {
+        let solved = || Outcome::Solved("1".to_string());
+        let records = [
+            record(1, 1, solved(), Verdict::Correct),
+            record(1, 2, solved(), Verdict::Unknown),
+            record(
+                2,
+                1,
+                solved(),
+                Verdict::Incorrect {
+                    expected: Answer::Integer(2),
+                },
+            ),
+            record(2, 2, Outcome::Unsolved, Verdict::Unknown),
+        ];
+
+        assert_eq!(
+            render_table(2022, &records),
+            "## Benchmarks 2022\n\n\
+             | Day | Part 1 | Part 2 |\n\
+             | :---: | :---: | :---: |\n\
+             | [Day 1](./src/y2022/day01.rs) | ⭐ `1.5ms` | `1.5ms` |\n\
+             | [Day 2](./src/y2022/day02.rs) | ❌ `1.5ms` | - |\n\
+             \n**Total: 4.50ms**\n"
+        );
+    }
+
+    #[test]
+    fn test_update() {
+        let readme = format!("# AoC\n{}\nold\n{}\nrest\n", BEGIN_MARKER, END_MARKER);
+        let updated = update(&readme, "| table |\n").unwrap();
+        assert_eq!(
+            updated,
+            format!(
+                "# AoC\n{}\n\n| table |\n{}\nrest\n",
+                BEGIN_MARKER, END_MARKER
+            )
+        );
+        assert_eq!(update(&updated, "| table |\n").unwrap(), updated);
+
+        assert!(update("# AoC\n", "").is_err());
+        assert!(update(BEGIN_MARKER, "").is_err());
+    }
+}
@@ -0,0 +1,782 @@
+use std::fmt::{self, Write as _};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::alloc::AllocStats;
+use crate::answers::{Answer, Verdict};
+use crate::bench::Stats;
+use crate::solution::{Outcome, PartResult};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// How run results are written to stdout.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable output with ANSI decorations.
+    #[default]
+    Text,
+    /// A single JSON array with one object per part.
+    Json,
+    /// A header row followed by one row per part.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: json, csv, text",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// What a [`Record`] measured: one of the parts of a day, or the parse step that days which
+/// [parse their input once](crate::solution::Solution::parse) run before them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Step {
+    Parse,
+    /// 1 or 2.
+    Part(u8),
+}
+
+impl Step {
+    /// The part number, or `None` for the parse step.
+    pub fn part(self) -> Option<u8> {
+        match self {
+            Step::Parse => None,
+            Step::Part(part) => Some(part),
+        }
+    }
+
+    /// The `step` field of the machine formats.
+    pub fn kind(self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::Part(_) => "part",
+        }
+    }
+
+    /// The inverse of [`kind`](Self::kind) and [`part`](Self::part).
+    pub fn from_fields(kind: &str, part: &str) -> Option<Self> {
+        match kind {
+            "parse" if part.is_empty() => Some(Step::Parse),
+            "part" => part.parse().ok().map(Step::Part),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+/// The result of one step of one day, as reported to the user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub step: Step,
+    pub result: PartResult,
+    /// Whether the timing was taken while other days ran in parallel, see `--jobs`.
+    pub contended: bool,
+    /// Only present in benchmark mode.
+    pub stats: Option<Stats>,
+    pub verdict: Verdict,
+}
+
+impl Record {
+    /// The answer of a solved part. The parse step never has one.
+    pub fn answer(&self) -> Option<&str> {
+        self.step.part().and(self.result.answer())
+    }
+
+    pub fn status(&self) -> &'static str {
+        match self.result.outcome {
+            Outcome::Solved(_) if self.step == Step::Parse => "parsed",
+            Outcome::Solved(_) => "solved",
+            Outcome::Unsolved => "unsolved",
+            Outcome::Error(_) => "error",
+            Outcome::TimedOut(_) => "timed_out",
+        }
+    }
+
+    /// Reads back a row of the CSV format, given the `header` row that came before it.
+    ///
+    /// Returns `None` for rows that do not fit the header, and for parts that timed out, as the
+    /// format does not keep their time limit.
+    pub fn from_csv(header: &[String], row: &[String]) -> Option<Self> {
+        let field = |name: &str| {
+            let index = header.iter().position(|column| column == name)?;
+            row.get(index).map(String::as_str)
+        };
+        let nanos = |name: &str| -> Option<Option<Duration>> {
+            Some(optional(field(name)?)?.map(Duration::from_nanos))
+        };
+
+        let outcome = match field("status")? {
+            "parsed" | "solved" => Outcome::Solved(field("answer")?.to_string()),
+            "unsolved" => Outcome::Unsolved,
+            "error" => Outcome::Error(field("error")?.to_string()),
+            _ => return None,
+        };
+        let verdict = match field("verdict")? {
+            "correct" => Verdict::Correct,
+            "incorrect" => Verdict::Incorrect {
+                expected: Answer::from_output(field("expected")?),
+            },
+            "unknown" => Verdict::Unknown,
+            _ => return None,
+        };
+        let stats = match optional(field("iterations")?)? {
+            Some(iterations) => Some(Stats {
+                iterations,
+                min: nanos("min_ns")??,
+                median: nanos("median_ns")??,
+                mean: nanos("mean_ns")??,
+                stddev: nanos("stddev_ns")??,
+            }),
+            None => None,
+        };
+        let alloc = match (
+            optional(field("peak_bytes")?)?,
+            optional(field("allocations")?)?,
+        ) {
+            (Some(peak_bytes), Some(allocations)) => Some(AllocStats {
+                peak_bytes,
+                allocations,
+            }),
+            _ => None,
+        };
+
+        Some(Record {
+            year: field("year")?.parse().ok()?,
+            day: field("day")?.parse().ok()?,
+            step: Step::from_fields(field("step")?, field("part")?)?,
+            result: PartResult {
+                outcome,
+                elapsed: nanos("elapsed_ns")??,
+                alloc,
+            },
+            contended: field("contended")?.parse().ok()?,
+            stats,
+            verdict,
+        })
+    }
+}
+
+/// Parses a CSV field that may be empty. `None` if it is not empty but does not parse.
+fn optional<T: FromStr>(value: &str) -> Option<Option<T>> {
+    match value {
+        "" => Some(None),
+        value => value.parse().ok().map(Some),
+    }
+}
+
+/// Aggregated over all records passed to a [`Reporter`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub total: Duration,
+    pub correct: usize,
+    pub unknown: usize,
+    /// `(day, part)` of every part that did not produce its known answer.
+    pub incorrect: Vec<(u8, u8)>,
+    /// `(day, part)` of every part that did not finish in time.
+    pub timed_out: Vec<(u8, u8)>,
+}
+
+impl Summary {
+    pub fn passed(&self) -> bool {
+        self.incorrect.is_empty() && self.timed_out.is_empty()
+    }
+
+    fn add(&mut self, record: &Record) {
+        self.total += record.result.elapsed;
+        let Step::Part(part) = record.step else {
+            return;
+        };
+        if let Outcome::TimedOut(_) = record.result.outcome {
+            self.timed_out.push((record.day, part));
+        }
+        match record.verdict {
+            Verdict::Correct => self.correct += 1,
+            Verdict::Incorrect { .. } => self.incorrect.push((record.day, part)),
+            Verdict::Unknown => self.unknown += 1,
+        }
+    }
+
+    fn render(&self, decorated: bool) -> String {
+        let (bold, italic, reset) = if decorated {
+            (ANSI_BOLD, ANSI_ITALIC, ANSI_RESET)
+        } else {
+            ("", "", "")
+        };
+        let list_parts = |parts: &[(u8, u8)]| {
+            parts
+                .iter()
+                .map(|(day, part)| format!("day {:02} part {}", day, part))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let incorrect = if self.incorrect.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", list_parts(&self.incorrect))
+        };
+        let mut rendered = format!(
+            "{}Total:{} {}{:.2}ms{}\n{}Answers:{} {} correct, {} incorrect{}, {} unknown",
+            bold,
+            reset,
+            italic,
+            self.total.as_secs_f64() * 1000_f64,
+            reset,
+            bold,
+            reset,
+            self.correct,
+            self.incorrect.len(),
+            incorrect,
+            self.unknown
+        );
+        if !self.timed_out.is_empty() {
+            write!(
+                rendered,
+                "\n{}Timed out:{} {}",
+                bold,
+                reset,
+                list_parts(&self.timed_out)
+            )
+            .unwrap();
+        }
+        rendered
+    }
+}
+
+const CSV_HEADER: &str = "year,day,step,part,answer,elapsed_ns,contended,status,error,verdict,\
+                          expected,iterations,min_ns,median_ns,mean_ns,stddev_ns,peak_bytes,\
+                          allocations";
+
+/// Writes records to stdout as they come in.
+pub struct Reporter {
+    format: Format,
+    records_written: usize,
+    summary: Summary,
+}
+
+impl Reporter {
+    pub fn new(format: Format) -> Self {
+        let reporter = Reporter {
+            format,
+            records_written: 0,
+            summary: Summary::default(),
+        };
+        match format {
+            Format::Text => {}
+            Format::Json => println!("["),
+            Format::Csv => println!("{}", CSV_HEADER),
+        }
+        reporter
+    }
+
+    /// Announces that the following records belong to `day`. Only affects text output.
+    pub fn day(&mut self, day: u8) {
+        if self.format == Format::Text {
+            println!("----------");
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("----------");
+        }
+    }
+
+    pub fn record(&mut self, record: &Record) {
+        match self.format {
+            Format::Text => {
+                match record.step {
+                    Step::Parse => {
+                        println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+                        println!("{}", render_text(record).trim_start());
+                    }
+                    Step::Part(part) => {
+                        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+                        println!(
+                            "{} {}",
+                            render_text(record),
+                            render_text_verdict(&record.verdict)
+                        );
+                    }
+                }
+                if let Some(stats) = &record.stats {
+                    println!("{}", render_text_stats(stats));
+                }
+            }
+            Format::Json => {
+                if self.records_written > 0 {
+                    println!(",");
+                }
+                print!("  {}", render_json(record));
+            }
+            Format::Csv => println!("{}", render_csv(record)),
+        }
+        self.records_written += 1;
+        self.summary.add(record);
+    }
+
+    /// Ends the output and returns the summary of all records.
+    ///
+    /// With `show_summary`, the total time and answer verdicts are printed as well. Machine
+    /// formats print them to stderr, so that stdout stays parseable.
+    pub fn finish(self, show_summary: bool) -> Summary {
+        if self.format == Format::Json {
+            if self.records_written > 0 {
+                println!();
+            }
+            println!("]");
+        }
+
+        if show_summary {
+            match self.format {
+                Format::Text => println!("{}", self.summary.render(true)),
+                Format::Json | Format::Csv => eprintln!("{}", self.summary.render(false)),
+            }
+        }
+
+        self.summary
+    }
+}
+
+fn render_text(record: &Record) -> String {
+    match &record.result.outcome {
+        Outcome::Solved(answer) => format!(
+            "{} {}(elapsed: {:.2?}{}{}){}",
+            answer,
+            ANSI_ITALIC,
+            record.result.elapsed,
+            record.result.alloc.map_or(String::new(), |alloc| format!(
+                ", {}",
+                render_text_alloc(&alloc)
+            )),
+            if record.contended { ", contended" } else { "" },
+            ANSI_RESET
+        ),
+        Outcome::Unsolved => "not solved.".to_string(),
+        Outcome::Error(message) => format!("error: {}", message),
+        Outcome::TimedOut(limit) => format!("timed out after {:.2?}.", limit),
+    }
+}
+
+fn render_text_alloc(alloc: &AllocStats) -> String {
+    format!(
+        "peak: {}, {} allocations",
+        render_bytes(alloc.peak_bytes),
+        alloc.allocations
+    )
+}
+
+fn render_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024_f64;
+    let mut unit = 0;
+    while value >= 1024_f64 && unit < UNITS.len() - 1 {
+        value /= 1024_f64;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+fn render_text_verdict(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Correct => "✅".to_string(),
+        Verdict::Incorrect { expected } => format!("❌ expected: {}", expected),
+        Verdict::Unknown => "❔".to_string(),
+    }
+}
+
+fn render_text_stats(stats: &Stats) -> String {
+    format!(
+        "{}min: {:.2?}, median: {:.2?}, mean: {:.2?} ± {:.2?} ({} runs){}",
+        ANSI_ITALIC,
+        stats.min,
+        stats.median,
+        stats.mean,
+        stats.stddev,
+        stats.iterations,
+        ANSI_RESET
+    )
+}
+
+fn render_json(record: &Record) -> String {
+    let optional = |value: Option<&str>| value.map_or("null".to_string(), json_string);
+    let error = match &record.result.outcome {
+        Outcome::Error(message) => Some(message.as_str()),
+        _ => None,
+    };
+    let bench = record.stats.map_or("null".to_string(), |stats| {
+        format!(
+            "{{\"iterations\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+            stats.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos(),
+        )
+    });
+    let alloc = record.result.alloc.map_or("null".to_string(), |alloc| {
+        format!(
+            "{{\"peak_bytes\":{},\"allocations\":{}}}",
+            alloc.peak_bytes, alloc.allocations
+        )
+    });
+    let expected = match &record.verdict {
+        Verdict::Incorrect {
+            expected: Answer::Integer(value),
+        } => value.to_string(),
+        Verdict::Incorrect {
+            expected: Answer::Text(value),
+        } => json_string(value),
+        _ => "null".to_string(),
+    };
+    format!(
+        "{{\"year\":{},\"day\":{},\"step\":\"{}\",\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"alloc\":{},\"contended\":{},\
+         \"status\":\"{}\",\"error\":{},\"verdict\":\"{}\",\"expected\":{},\"bench\":{}}}",
+        record.year,
+        record.day,
+        record.step.kind(),
+        record
+            .step
+            .part()
+            .map_or("null".to_string(), |part| part.to_string()),
+        optional(record.answer()),
+        record.result.elapsed.as_nanos(),
+        alloc,
+        record.contended,
+        record.status(),
+        optional(error),
+        record.verdict.as_str(),
+        expected,
+        bench,
+    )
+}
+
+fn render_csv(record: &Record) -> String {
+    let error = match &record.result.outcome {
+        Outcome::Error(message) => message.as_str(),
+        _ => "",
+    };
+    let bench = record.stats.map_or(",,,,".to_string(), |stats| {
+        format!(
+            "{},{},{},{},{}",
+            stats.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos(),
+        )
+    });
+    let alloc = record.result.alloc.map_or(",".to_string(), |alloc| {
+        format!("{},{}", alloc.peak_bytes, alloc.allocations)
+    });
+    let expected = match &record.verdict {
+        Verdict::Incorrect { expected } => csv_field(&expected.to_string()),
+        _ => String::new(),
+    };
+    format!(
+        "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+        record.year,
+        record.day,
+        record.step.kind(),
+        record
+            .step
+            .part()
+            .map_or(String::new(), |part| part.to_string()),
+        csv_field(record.answer().unwrap_or("")),
+        record.result.elapsed.as_nanos(),
+        record.contended,
+        record.status(),
+        csv_field(error),
+        record.verdict.as_str(),
+        expected,
+        bench,
+        alloc,
+    )
+}
+
+/// Splits CSV output into rows of fields, honouring quoted fields that span lines.
+pub fn parse_csv(output: &str) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = output.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\n', false) => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            ('\r', false) => {}
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                write!(out, "\\u{:04x}", c as u32).unwrap();
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(outcome: Outcome) -> Record {
+        Record {
+            year: 2022,
+            day: 10,
+            step: Step::Part(2),
+            result: PartResult {
+                outcome,
+                elapsed: Duration::from_nanos(1500),
+                alloc: None,
+            },
+            contended: false,
+            stats: None,
+            verdict: Verdict::Unknown,
+        }
+    }
+
+    #[test]
+    fn test_render_json() {
+        assert_eq!(
+            render_json(&record(Outcome::Solved("#.\n.#\n".to_string()))),
+            r##"{"year":2022,"day":10,"step":"part","part":2,"answer":"#.\n.#\n","elapsed_ns":1500,"alloc":null,"contended":false,"status":"solved","error":null,"verdict":"unknown","expected":null,"bench":null}"##
+        );
+        assert_eq!(
+            render_json(&record(Outcome::Error("bad \"input\"".to_string()))),
+            r#"{"year":2022,"day":10,"step":"part","part":2,"answer":null,"elapsed_ns":1500,"alloc":null,"contended":false,"status":"error","error":"bad \"input\"","verdict":"unknown","expected":null,"bench":null}"#
+        );
+    }
+
+    #[test]
+    fn test_render_csv() {
+        assert_eq!(
+            render_csv(&record(Outcome::Solved("42".to_string()))),
+            "2022,10,part,2,42,1500,false,solved,,unknown,,,,,,,,"
+        );
+        assert_eq!(
+            render_csv(&record(Outcome::Solved("a,\"b\"".to_string()))),
+            "2022,10,part,2,\"a,\"\"b\"\"\",1500,false,solved,,unknown,,,,,,,,"
+        );
+        assert_eq!(
+            render_csv(&record(Outcome::Unsolved)),
+            "2022,10,part,2,,1500,false,unsolved,,unknown,,,,,,,,"
+        );
+    }
+
+    #[test]
+    fn test_render_parse() {
+        let mut parse = record(Outcome::Solved(String::new()));
+        parse.step = Step::Parse;
+
+        assert_eq!(parse.status(), "parsed");
+        assert!(render_json(&parse).starts_with(
+            r#"{"year":2022,"day":10,"step":"parse","part":null,"answer":null,"elapsed_ns":1500,"#
+        ));
+        assert_eq!(
+            render_csv(&parse),
+            "2022,10,parse,,,1500,false,parsed,,unknown,,,,,,,,"
+        );
+        assert_eq!(Step::from_fields("parse", ""), Some(Step::Parse));
+        assert_eq!(Step::from_fields("part", "2"), Some(Step::Part(2)));
+        assert_eq!(Step::from_fields("part", ""), None);
+    }
+
+    #[test]
+    fn test_render_stats() {
+        let mut record = record(Outcome::Solved("42".to_string()));
+        record.stats = Some(Stats {
+            iterations: 3,
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(20),
+            mean: Duration::from_nanos(25),
+            stddev: Duration::from_nanos(5),
+        });
+
+        assert!(render_json(&record).ends_with(
+            r#""bench":{"iterations":3,"min_ns":10,"median_ns":20,"mean_ns":25,"stddev_ns":5}}"#
+        ));
+        assert_eq!(
+            render_csv(&record),
+            "2022,10,part,2,42,1500,false,solved,,unknown,,3,10,20,25,5,,"
+        );
+    }
+
+    #[test]
+    fn test_render_alloc() {
+        let mut record = record(Outcome::Solved("42".to_string()));
+        record.result.alloc = Some(AllocStats {
+            peak_bytes: 3 * 1024 * 1024 / 2,
+            allocations: 12,
+        });
+
+        assert!(render_text(&record).contains("peak: 1.50 MiB, 12 allocations"));
+        assert!(render_json(&record).contains(r#""alloc":{"peak_bytes":1572864,"allocations":12}"#));
+        assert!(render_csv(&record).ends_with(",1572864,12"));
+        assert_eq!(render_bytes(512), "512 B");
+    }
+
+    #[test]
+    fn test_render_verdict() {
+        let mut record = record(Outcome::Solved("41".to_string()));
+        record.verdict = Verdict::Incorrect {
+            expected: Answer::Text("42".to_string()),
+        };
+
+        assert!(render_json(&record).contains(r#""verdict":"incorrect","expected":"42""#));
+        assert_eq!(
+            render_csv(&record),
+            "2022,10,part,2,41,1500,false,solved,,incorrect,42,,,,,,,"
+        );
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let header = &parse_csv(CSV_HEADER)[0];
+        let read_back = |record: &Record| {
+            let row = &parse_csv(&render_csv(record))[0];
+            Record::from_csv(header, row)
+        };
+
+        let mut solved = record(Outcome::Solved("#.\n.#, \"x\"".to_string()));
+        solved.contended = true;
+        solved.verdict = Verdict::Incorrect {
+            expected: Answer::Integer(42),
+        };
+        solved.stats = Some(Stats {
+            iterations: 3,
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(20),
+            mean: Duration::from_nanos(25),
+            stddev: Duration::from_nanos(5),
+        });
+        solved.result.alloc = Some(AllocStats {
+            peak_bytes: 1024,
+            allocations: 12,
+        });
+        let mut parse = record(Outcome::Solved(String::new()));
+        parse.step = Step::Parse;
+
+        for record in [
+            solved,
+            parse,
+            record(Outcome::Unsolved),
+            record(Outcome::Error("bad, input".to_string())),
+        ] {
+            assert_eq!(read_back(&record), Some(record));
+        }
+        assert_eq!(read_back(&record(Outcome::TimedOut(Duration::ZERO))), None);
+        assert_eq!(Record::from_csv(header, &["2022".to_string()]), None);
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut summary = Summary::default();
+        let mut correct = record(Outcome::Solved("42".to_string()));
+        correct.verdict = Verdict::Correct;
+        summary.add(&correct);
+        summary.add(&record(Outcome::Unsolved));
+        let mut parse = record(Outcome::Solved(String::new()));
+        parse.step = Step::Parse;
+        summary.add(&parse);
+        assert!(summary.passed());
+
+        let mut incorrect = record(Outcome::Unsolved);
+        incorrect.verdict = Verdict::Incorrect {
+            expected: Answer::Integer(42),
+        };
+        summary.add(&incorrect);
+        assert!(!summary.passed());
+        // the parse step counts towards the total, but has no verdict.
+        assert_eq!(
+            summary.render(false),
+            "Total: 0.01ms\nAnswers: 1 correct, 1 incorrect (day 10 part 2), 1 unknown"
+        );
+    }
+
+    #[test]
+    fn test_summary_timed_out() {
+        let mut summary = Summary::default();
+        let timed_out = record(Outcome::TimedOut(Duration::from_secs(10)));
+        assert_eq!(timed_out.status(), "timed_out");
+        summary.add(&timed_out);
+
+        assert!(!summary.passed());
+        assert_eq!(
+            summary.render(false),
+            "Total: 0.00ms\nAnswers: 0 correct, 0 incorrect, 1 unknown\nTimed out: day 10 part 2"
+        );
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+        assert_eq!("text".parse(), Ok(Format::Text));
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,538 @@
+use std::convert::Infallible;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::process::{self, Command, Stdio};
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answers::{Answers, Verdict};
+use crate::bench::{bench_part, BenchConfig, Stats};
+use crate::report::{parse_csv, Format, Record, Reporter, Step};
+use crate::solution::{catching, parse_catching, solve_catching, Outcome, PartResult, Solution};
+use crate::{watch, years};
+
+/// Where a day's input is read from.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Input {
+    /// `yYYYY/inputs/NN.txt` in the [data directory](crate::data_dir).
+    #[default]
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl FromStr for Input {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Input::Stdin,
+            path => Input::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl Input {
+    pub fn read(&self, year: u16, day: u8) -> Result<String, String> {
+        match self {
+            Input::Default => crate::try_read_file(year, "inputs", day)
+                .map_err(|e| format!("could not open input file: {}", e)),
+            Input::File(path) => fs::read_to_string(path)
+                .map_err(|e| format!("could not open input file {}: {}", path.display(), e)),
+            Input::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|e| format!("could not read input from stdin: {}", e))
+            }
+        }
+    }
+}
+
+/// Options shared by the per-day binaries and the all-days runner.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub format: Format,
+    /// Benchmark each part instead of timing a single run.
+    pub bench: Option<BenchConfig>,
+    /// Only set by `cargo solve`, see [`run_day`].
+    pub input: Input,
+    /// Only run this part instead of both.
+    pub part: Option<u8>,
+}
+
+impl Options {
+    /// Consumes the shared options from `args`, leaving binary-specific ones in place.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let format = args.opt_value_from_str("--format")?.unwrap_or_default();
+
+        let warmup: Option<u32> = args.opt_value_from_str("--warmup")?;
+        let budget_ms: Option<u64> = args.opt_value_from_str("--bench-time")?;
+        let bench = if args.contains("--bench") || warmup.is_some() || budget_ms.is_some() {
+            let defaults = BenchConfig::default();
+            Some(BenchConfig {
+                warmup: warmup.unwrap_or(defaults.warmup),
+                budget: budget_ms.map_or(defaults.budget, Duration::from_millis),
+            })
+        } else {
+            None
+        };
+
+        let part = args.opt_value_from_fn("--part", parse_part)?;
+
+        Ok(Options {
+            format,
+            bench,
+            input: Input::Default,
+            part,
+        })
+    }
+
+    /// The arguments that [`from_args`](Self::from_args) turns back into these options. The
+    /// input is left out, as only `cargo solve` reads it.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec!["--format".to_string(), self.format.to_string()];
+        if let Some(config) = &self.bench {
+            args.extend([
+                "--warmup".to_string(),
+                config.warmup.to_string(),
+                "--bench-time".to_string(),
+                config.budget.as_millis().to_string(),
+            ]);
+        }
+        if let Some(part) = self.part {
+            args.extend(["--part".to_string(), part.to_string()]);
+        }
+        args
+    }
+
+    /// The parts to run, in order.
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+/// Parses a part number, `1` or `2`.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("invalid part \"{}\", expected 1 or 2", s)),
+    }
+}
+
+/// Runs the selected parts of `solution` against the day's input, reports the results in
+/// `options.format` and exits with a nonzero status if an answer is incorrect.
+///
+/// See [`solve_main`] for the entry point of `cargo solve`.
+pub fn run_day(solution: &dyn Solution, options: &Options) {
+    // known answers belong to the default input, so other inputs are not checked against them.
+    let answers = match options.input {
+        Input::Default => Answers::load(solution.year(), solution.day()),
+        Input::File(_) | Input::Stdin => Ok(Answers::default()),
+    };
+    let answers = match answers {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to load answers: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let mut reporter = Reporter::new(options.format);
+    solve_day(solution, &answers, options, |record| {
+        reporter.record(&record)
+    });
+    if !reporter.finish(false).passed() {
+        process::exit(1);
+    }
+}
+
+/// Reads the day's input, runs the selected parts against it and checks the results against
+/// `answers`. Passes each record to `emit` as soon as it is done. Days with a parse step start
+/// with a record of it, see [`Step::Parse`].
+///
+/// Never panics: a missing input or a panicking solver is reported as an error record.
+pub fn solve_day(
+    solution: &dyn Solution,
+    answers: &Answers,
+    options: &Options,
+    emit: impl FnMut(Record),
+) {
+    let input = options.input.read(solution.year(), solution.day());
+    solve_parts(solution, &input, answers, options, emit);
+}
+
+/// Like [`solve_day`], but runs the day in the child process `command` and kills it if its parts
+/// have not finished after `timeout`. The parts that did not finish are reported as timed out.
+///
+/// `command` must run only this day with `options` and print its records in the CSV format, like
+/// `cargo all --days <day> --format csv` does. Its stderr is discarded.
+pub fn solve_day_in_child(
+    mut command: Command,
+    solution: &dyn Solution,
+    answers: &Answers,
+    options: &Options,
+    timeout: Duration,
+    mut emit: impl FnMut(Record),
+) {
+    let deadline = Instant::now() + timeout;
+    let parts = options.parts();
+    let unfinished = |part, outcome: Outcome| Record {
+        year: solution.year(),
+        day: solution.day(),
+        step: Step::Part(part),
+        verdict: Verdict::check(answers.get(part), &outcome),
+        result: PartResult {
+            outcome,
+            elapsed: Duration::ZERO,
+            alloc: None,
+        },
+        contended: false,
+        stats: None,
+    };
+
+    let spawned = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => {
+            let error = Outcome::Error(format!("could not start the day: {}", e));
+            parts
+                .iter()
+                .for_each(|&part| emit(unfinished(part, error.clone())));
+            return;
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    if let Some(stdout) = child.stdout.take() {
+        thread::spawn(move || {
+            let mut header = None;
+            let mut row = String::new();
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                row.push_str(&line);
+                row.push('\n');
+                // quoted answers may span several lines, the row ends once all quotes are closed.
+                if row.matches('"').count() % 2 == 1 {
+                    continue;
+                }
+                let fields = parse_csv(&std::mem::take(&mut row))
+                    .pop()
+                    .unwrap_or_default();
+                match &header {
+                    None => header = Some(fields),
+                    Some(header) => {
+                        let record = Record::from_csv(header, &fields);
+                        // the receiver is gone once the deadline passed.
+                        if record.is_some_and(|record| sender.send(record).is_err()) {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    let mut done = vec![];
+    let outcome = loop {
+        if done.len() == parts.len() {
+            break None;
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match receiver.recv_timeout(remaining) {
+            Ok(record) => {
+                done.extend(record.step.part());
+                emit(record);
+            }
+            Err(RecvTimeoutError::Timeout) => break Some(Outcome::TimedOut(timeout)),
+            Err(RecvTimeoutError::Disconnected) => {
+                let error = "the day exited without reporting a result, e.g. because it crashed";
+                break Some(Outcome::Error(error.to_string()));
+            }
+        }
+    };
+    let _ = child.kill();
+    let _ = child.wait();
+
+    if let Some(outcome) = outcome {
+        for &part in parts.iter().filter(|part| !done.contains(part)) {
+            emit(unfinished(part, outcome.clone()));
+        }
+    }
+}
+
+/// Runs the day's parse step, if any, and the selected parts against `input`. Passes each record
+/// to `emit` as soon as it is done.
+fn solve_parts(
+    solution: &dyn Solution,
+    input: &Result<String, String>,
+    answers: &Answers,
+    options: &Options,
+    mut emit: impl FnMut(Record),
+) {
+    let record = |step, (result, stats): (PartResult, Option<Stats>)| {
+        let verdict = match step {
+            Step::Parse => Verdict::Unknown,
+            Step::Part(part) => Verdict::check(answers.get(part), &result.outcome),
+        };
+        Record {
+            year: solution.year(),
+            day: solution.day(),
+            step,
+            result,
+            contended: false,
+            stats,
+            verdict,
+        }
+    };
+    let measure = |run: &dyn Fn() -> PartResult| match &options.bench {
+        Some(config) => bench_part(run, config),
+        None => (run(), None),
+    };
+
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            for part in options.parts() {
+                emit(record(
+                    Step::Part(part),
+                    (PartResult::error(e.clone()), None),
+                ));
+            }
+            return;
+        }
+    };
+
+    match parse_catching(solution, input) {
+        Some(parsed) => {
+            let parse_result = match &options.bench {
+                Some(config) => bench_part(
+                    || parse_catching(solution, input).map_or(parsed.result.clone(), |p| p.result),
+                    config,
+                ),
+                None => (parsed.result.clone(), None),
+            };
+            emit(record(Step::Parse, parse_result));
+            for part in options.parts() {
+                emit(record(
+                    Step::Part(part),
+                    measure(&|| catching(|| parsed.solve(part))),
+                ));
+            }
+        }
+        None => {
+            for part in options.parts() {
+                emit(record(
+                    Step::Part(part),
+                    measure(&|| solve_catching(solution, part, input)),
+                ));
+            }
+        }
+    }
+}
+
+/// The arguments of `cargo solve` and of the per-day binaries.
+struct SolveArgs {
+    year: u16,
+    day: u8,
+    options: Options,
+    watch: bool,
+}
+
+/// Parses the arguments of `cargo solve`, or of a per-day binary if `day` is given, which then
+/// takes no day argument.
+fn parse_solve_args(day: Option<u8>) -> Result<SolveArgs, pico_args::Error> {
+    // cargo passes everything after the day on to us, including a `--`.
+    let mut args =
+        pico_args::Arguments::from_vec(env::args_os().skip(1).filter(|arg| arg != "--").collect());
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(years::latest);
+    let mut options = Options::from_args(&mut args)?;
+    options.input = args.opt_value_from_str("--input")?.unwrap_or_default();
+    let watch = args.contains("--watch");
+    let day = match day {
+        Some(day) => day,
+        None => args.free_from_str()?,
+    };
+
+    let remaining = args.finish();
+    if let Some(arg) = remaining.first() {
+        let hint = if arg == "--release" {
+            format!(
+                ", cargo options go before the day: `cargo solve --release {:02}`",
+                day
+            )
+        } else {
+            String::new()
+        };
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}{}", arg, hint),
+        });
+    }
+    Ok(SolveArgs {
+        year,
+        day,
+        options,
+        watch,
+    })
+}
+
+/// Runs a day as selected by the command line arguments, see [`run_day`].
+///
+/// Entry point of `cargo solve` and of the per-day binaries `src/bin/NN.rs`, which pass their
+/// `day` and run it for `--year` like `cargo solve` does.
+pub fn solve_main(day: Option<u8>) {
+    let args = match parse_solve_args(day) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if args.watch {
+        if args.options.input == Input::Stdin || args.options.bench.is_some() {
+            eprintln!("--watch cannot be combined with --input - or --bench");
+            process::exit(1);
+        }
+        // the day may not be registered yet, the watcher picks it up once it is.
+        watch::watch(args.year, args.day, &args.options);
+    }
+
+    match years::find_day(args.year, args.day) {
+        Some(solution) => run_day(solution, &args.options),
+        None => {
+            eprintln!(
+                "Day {} of {} has no solution yet. Try `cargo scaffold {} --year {}`.",
+                args.day, args.year, args.day, args.year
+            );
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Day, ParsedDay};
+
+    static DAY: Day<Option<u32>, Option<u32>> = Day {
+        year: 2022,
+        day: 1,
+        part_one: |_| None,
+        part_two: |_| None,
+    };
+
+    /// A child process that prints `output` and then does as `then` says.
+    fn child(output: &str, then: &str) -> Command {
+        let mut command = Command::new("sh");
+        command
+            .env("OUTPUT", output)
+            .args(["-c", &format!("printf '%s' \"$OUTPUT\"; {}", then)]);
+        command
+    }
+
+    #[test]
+    fn test_solve_day_in_child() {
+        let output =
+            "year,day,step,part,answer,elapsed_ns,contended,status,error,verdict,expected,\
+                      iterations,min_ns,median_ns,mean_ns,stddev_ns,peak_bytes,allocations\n\
+                      2022,1,part,1,\"#.\n.#\",100,false,solved,,unknown,,,,,,,,\n";
+        let timeout = Duration::from_millis(200);
+        let options = Options::default();
+
+        let solve = |command| {
+            let mut records = vec![];
+            let answers = Answers::default();
+            solve_day_in_child(command, &DAY, &answers, &options, timeout, |record| {
+                records.push(record)
+            });
+            records
+        };
+
+        // part two never finishes, so the child is killed instead of sleeping on.
+        let started = Instant::now();
+        let records = solve(child(output, "sleep 10"));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].result.answer(), Some("#.\n.#"));
+        assert_eq!(records[1].result.outcome, Outcome::TimedOut(timeout));
+
+        let records = solve(child(output, "exit 1"));
+        assert_eq!(records.len(), 2);
+        assert!(matches!(records[1].result.outcome, Outcome::Error(_)));
+    }
+
+    #[test]
+    fn test_options_to_args() {
+        let options = Options {
+            format: Format::Csv,
+            bench: Some(BenchConfig {
+                warmup: 1,
+                budget: Duration::from_millis(250),
+            }),
+            input: Input::Default,
+            part: Some(2),
+        };
+        let args = options.to_args().into_iter().map(Into::into).collect();
+        let parsed = Options::from_args(&mut pico_args::Arguments::from_vec(args)).unwrap();
+        assert_eq!(parsed.format, options.format);
+        assert_eq!(parsed.bench, options.bench);
+        assert_eq!(parsed.part, options.part);
+    }
+
+    #[test]
+    fn test_solve_parsed_day() {
+        static DAY: ParsedDay<usize, Option<usize>, Option<usize>> = ParsedDay {
+            year: 2022,
+            day: 1,
+            parse: |input| Ok(input.len()),
+            part_one: |len| Some(len + 1),
+            part_two: |len| Some(len + 2),
+        };
+        let options = Options {
+            input: Input::File(PathBuf::from("Cargo.toml")),
+            ..Options::default()
+        };
+        let len = fs::read_to_string("Cargo.toml").unwrap().len();
+
+        let mut records = vec![];
+        solve_day(&DAY, &Answers::default(), &options, |record| {
+            records.push(record)
+        });
+        let steps: Vec<Step> = records.iter().map(|record| record.step).collect();
+        assert_eq!(steps, [Step::Parse, Step::Part(1), Step::Part(2)]);
+        assert_eq!(
+            records[2].result.answer(),
+            Some((len + 2).to_string().as_str())
+        );
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(parse_part("2"), Ok(2));
+        assert!(parse_part("3").is_err());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!("-".parse(), Ok(Input::Stdin));
+        assert_eq!(
+            "stress/16.txt".parse(),
+            Ok(Input::File(PathBuf::from("stress/16.txt")))
+        );
+    }
+}
@@ -0,0 +1,340 @@
+use std::any::Any;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::alloc::{self, AllocStats};
+use crate::error::SolveError;
+
+/// What a single part produced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(String),
+    /// The solver returned `None` or [`SolveError::Unsolved`].
+    Unsolved,
+    /// The part could not be run, e.g. because the solver panicked or the input was missing.
+    Error(String),
+    /// The part did not finish within the given time limit.
+    TimedOut(Duration),
+}
+
+/// The outcome of running a single part of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PartResult {
+    pub outcome: Outcome,
+    /// Time spent inside the solver, excluding input reads and formatting.
+    pub elapsed: Duration,
+    /// Heap usage of the solver. Only present with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+}
+
+impl PartResult {
+    pub fn error(message: impl Into<String>) -> Self {
+        PartResult {
+            outcome: Outcome::Error(message.into()),
+            elapsed: Duration::ZERO,
+            alloc: None,
+        }
+    }
+
+    pub fn answer(&self) -> Option<&str> {
+        match &self.outcome {
+            Outcome::Solved(answer) => Some(answer),
+            _ => None,
+        }
+    }
+}
+
+/// A return type of `part_one` and `part_two`.
+pub trait PartOutput {
+    fn into_outcome(self) -> Outcome;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn into_outcome(self) -> Outcome {
+        match self {
+            Some(answer) => Outcome::Solved(answer.to_string()),
+            None => Outcome::Unsolved,
+        }
+    }
+}
+
+impl<T: Display> PartOutput for Result<T, SolveError> {
+    fn into_outcome(self) -> Outcome {
+        match self {
+            Ok(answer) => Outcome::Solved(answer.to_string()),
+            Err(e) => e.into(),
+        }
+    }
+}
+
+impl From<SolveError> for Outcome {
+    fn from(e: SolveError) -> Self {
+        match e {
+            SolveError::Unsolved => Outcome::Unsolved,
+            e => Outcome::Error(e.to_string()),
+        }
+    }
+}
+
+/// A day's puzzle, runnable without knowing the concrete answer types.
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    /// Runs `part` (1 or 2) against `input`, including any parse step.
+    ///
+    /// Panics if `part` is neither 1 nor 2.
+    fn solve(&self, part: u8, input: &str) -> PartResult;
+
+    /// Runs the day's separate parse step against `input`, so that both parts can reuse its
+    /// result. `None` if the day parses the input in each part instead.
+    fn parse(&self, _input: &str) -> Option<ParsedInput<'_>> {
+        None
+    }
+}
+
+/// A day's input after its parse step, ready to run either part. See [`Solution::parse`].
+pub struct ParsedInput<'a> {
+    /// What the parse step took. Its outcome is an empty [`Outcome::Solved`] on success,
+    /// otherwise every part fails with the same outcome.
+    pub result: PartResult,
+    solve: Box<dyn Fn(u8) -> PartResult + 'a>,
+}
+
+impl<'a> ParsedInput<'a> {
+    pub fn new(result: PartResult, solve: impl Fn(u8) -> PartResult + 'a) -> Self {
+        ParsedInput {
+            result,
+            solve: Box::new(solve),
+        }
+    }
+
+    /// A parse step that did not produce a value, so that every part ends with `result`'s outcome.
+    pub fn failed(result: PartResult) -> Self {
+        let outcome = result.outcome.clone();
+        ParsedInput::new(result, move |_| PartResult {
+            outcome: outcome.clone(),
+            elapsed: Duration::ZERO,
+            alloc: None,
+        })
+    }
+
+    /// Runs `part` (1 or 2) against the parsed input, timing only the part itself.
+    ///
+    /// Panics if `part` is neither 1 nor 2.
+    pub fn solve(&self, part: u8) -> PartResult {
+        (self.solve)(part)
+    }
+}
+
+/// Pairs a day's `part_one` and `part_two` functions into a [`Solution`].
+pub struct Day<A, B> {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: fn(&str) -> A,
+    pub part_two: fn(&str) -> B,
+}
+
+impl<A: PartOutput, B: PartOutput> Solution for Day<A, B> {
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn solve(&self, part: u8, input: &str) -> PartResult {
+        match part {
+            1 => run_part(|| (self.part_one)(input)),
+            2 => run_part(|| (self.part_two)(input)),
+            _ => panic!("invalid part {}", part),
+        }
+    }
+}
+
+/// Like [`Day`], for days that parse their input once into a `P` that both parts take.
+pub struct ParsedDay<P, A, B> {
+    pub year: u16,
+    pub day: u8,
+    pub parse: fn(&str) -> Result<P, SolveError>,
+    pub part_one: fn(&P) -> A,
+    pub part_two: fn(&P) -> B,
+}
+
+impl<P, A: PartOutput, B: PartOutput> ParsedDay<P, A, B> {
+    fn solve_parsed(&self, part: u8, parsed: &P) -> PartResult {
+        match part {
+            1 => run_part(|| (self.part_one)(parsed)),
+            2 => run_part(|| (self.part_two)(parsed)),
+            _ => panic!("invalid part {}", part),
+        }
+    }
+}
+
+impl<P, A: PartOutput, B: PartOutput> Solution for ParsedDay<P, A, B> {
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn solve(&self, part: u8, input: &str) -> PartResult {
+        let parsed = self
+            .parse(input)
+            .expect("parsed days always have a parse step");
+        let mut result = parsed.solve(part);
+        result.elapsed += parsed.result.elapsed;
+        result
+    }
+
+    fn parse(&self, input: &str) -> Option<ParsedInput<'_>> {
+        let ((parsed, elapsed), alloc) = alloc::measure(|| {
+            let timer = Instant::now();
+            let parsed = (self.parse)(input);
+            (parsed, timer.elapsed())
+        });
+        let result = |outcome| PartResult {
+            outcome,
+            elapsed,
+            alloc,
+        };
+
+        Some(match parsed {
+            Ok(parsed) => ParsedInput::new(result(Outcome::Solved(String::new())), move |part| {
+                self.solve_parsed(part, &parsed)
+            }),
+            Err(e) => ParsedInput::failed(result(e.into())),
+        })
+    }
+}
+
+/// Runs `func`, timing and measuring only the call itself.
+pub fn run_part<T: PartOutput>(func: impl FnOnce() -> T) -> PartResult {
+    let ((result, elapsed), alloc) = alloc::measure(|| {
+        let timer = Instant::now();
+        let result = func();
+        (result, timer.elapsed())
+    });
+    PartResult {
+        outcome: result.into_outcome(),
+        elapsed,
+        alloc,
+    }
+}
+
+/// Like [`Solution::solve`], but turns a panicking solver into an [`Outcome::Error`].
+pub fn solve_catching(solution: &dyn Solution, part: u8, input: &str) -> PartResult {
+    catching(|| solution.solve(part, input))
+}
+
+/// Like [`Solution::parse`], but turns a panicking parse step into a failed [`ParsedInput`].
+pub fn parse_catching<'a>(solution: &'a dyn Solution, input: &str) -> Option<ParsedInput<'a>> {
+    panic::catch_unwind(AssertUnwindSafe(|| solution.parse(input)))
+        .unwrap_or_else(|payload| Some(ParsedInput::failed(panicked(payload))))
+}
+
+/// Runs `run`, turning a panic into an [`Outcome::Error`].
+pub fn catching(run: impl FnOnce() -> PartResult) -> PartResult {
+    panic::catch_unwind(AssertUnwindSafe(run)).unwrap_or_else(panicked)
+}
+
+fn panicked(payload: Box<dyn Any + Send>) -> PartResult {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic payload".to_string());
+    PartResult::error(format!("panicked: {}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_solve() {
+        let day = Day {
+            year: 2022,
+            day: 1,
+            part_one: |input| Some(input.len()),
+            part_two: |_| None::<u32>,
+        };
+
+        assert_eq!(day.day(), 1);
+        assert!(day.parse("abc").is_none());
+        assert_eq!(day.solve(1, "abc").answer(), Some("3"));
+        assert_eq!(day.solve(2, "abc").outcome, Outcome::Unsolved);
+    }
+
+    #[test]
+    fn test_result_outputs() {
+        let day = Day {
+            year: 2022,
+            day: 1,
+            part_one: |input| {
+                input
+                    .parse::<u32>()
+                    .map_err(|e| SolveError::Invalid(e.to_string()))
+            },
+            part_two: |_| Err::<u32, _>(SolveError::Unsolved),
+        };
+
+        assert_eq!(day.solve(1, "12").answer(), Some("12"));
+        assert_eq!(
+            day.solve(1, "x").outcome,
+            Outcome::Error("invalid input: invalid digit found in string".to_string())
+        );
+        assert_eq!(day.solve(2, "12").outcome, Outcome::Unsolved);
+    }
+
+    #[test]
+    fn test_solve_catching() {
+        let day = Day {
+            year: 2022,
+            day: 1,
+            part_one: |_| -> Option<u32> { panic!("oh no") },
+            part_two: |_| Some(2),
+        };
+
+        assert_eq!(
+            solve_catching(&day, 1, "").outcome,
+            Outcome::Error("panicked: oh no".to_string())
+        );
+        assert_eq!(solve_catching(&day, 2, "").answer(), Some("2"));
+    }
+
+    #[test]
+    fn test_parsed_day() {
+        let day = ParsedDay {
+            year: 2022,
+            day: 1,
+            parse: |input| {
+                input
+                    .parse::<u32>()
+                    .map_err(|e| SolveError::Invalid(e.to_string()))
+            },
+            part_one: |n| Some(n + 1),
+            part_two: |n| -> Option<u32> { panic!("{} is too much", n) },
+        };
+
+        let parsed = parse_catching(&day, "12").unwrap();
+        assert_eq!(parsed.result.answer(), Some(""));
+        assert_eq!(parsed.solve(1).answer(), Some("13"));
+        assert_eq!(
+            catching(|| parsed.solve(2)).outcome,
+            Outcome::Error("panicked: 12 is too much".to_string())
+        );
+        assert_eq!(day.solve(1, "12").answer(), Some("13"));
+
+        let failed = Outcome::Error("invalid input: invalid digit found in string".to_string());
+        let parsed = parse_catching(&day, "x").unwrap();
+        assert_eq!(parsed.result.outcome, failed);
+        assert_eq!(parsed.solve(1).outcome, failed);
+        assert_eq!(day.solve(1, "x").outcome, failed);
+    }
+}
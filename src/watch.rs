@@ -0,0 +1,286 @@
+//! Re-runs a day whenever its source, input or examples change, see `cargo solve --watch`.
+//!
+//! The watcher cannot reload its own code, so it runs `cargo run --bin solve` and `cargo test` for
+//! the day in child processes and compares the answers from one run to the next.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::report::{parse_csv, Step};
+use crate::runner::{Input, Options};
+use crate::{ANSI_BOLD, ANSI_RESET};
+
+/// How often the watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification time of every watched file. Missing files are `None`, so that creating or
+/// deleting them counts as a change too.
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// The files that affect the results of `day`: its module, its binary, its input and all of its
+/// examples.
+pub fn watched_paths(year: u16, day: u8, input: &Input) -> Result<Vec<PathBuf>, String> {
+    let year_dir = crate::year_dir(year).map_err(|e| e.to_string())?;
+    let mut paths = vec![
+        PathBuf::from(format!("src/y{}/day{:02}.rs", year, day)),
+        PathBuf::from(format!("src/bin/{:02}.rs", day)),
+    ];
+
+    paths.push(match input {
+        Input::File(path) => path.clone(),
+        _ => year_dir.join("inputs").join(format!("{:02}.txt", day)),
+    });
+
+    // examples are listed anew on every poll, so that added variants are picked up.
+    let prefix = format!("{:02}", day);
+    let examples_dir = year_dir.join("examples");
+    let mut examples: Vec<PathBuf> = fs::read_dir(&examples_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            let rest = file_name.strip_suffix(".txt")?.strip_prefix(&prefix)?;
+            (rest.is_empty() || rest.starts_with('-')).then(|| examples_dir.join(file_name))
+        })
+        .collect();
+    examples.sort();
+    paths.extend(examples);
+    Ok(paths)
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    paths
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+/// The paths that were added, removed or modified between two snapshots.
+fn changed_paths(previous: &Snapshot, current: &Snapshot) -> Vec<PathBuf> {
+    let mut changed: Vec<PathBuf> = current
+        .iter()
+        .filter(|(path, modified)| previous.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone())
+        .collect();
+    changed.extend(
+        previous
+            .keys()
+            .filter(|path| !current.contains_key(*path))
+            .cloned(),
+    );
+    changed
+}
+
+/// The result of every part in the CSV output of `cargo solve`, e.g. `24000 ✅`.
+fn results(output: &str) -> Option<BTreeMap<u8, String>> {
+    let mut rows = parse_csv(output).into_iter();
+    let header = rows.next()?;
+    let column = |name: &str| header.iter().position(|column| column == name);
+    let (step, part, answer, status, error, verdict) = (
+        column("step")?,
+        column("part")?,
+        column("answer")?,
+        column("status")?,
+        column("error")?,
+        column("verdict")?,
+    );
+
+    let mut results = BTreeMap::new();
+    for row in rows {
+        let field = |index: usize| row.get(index).map_or("", String::as_str);
+        // the parse step has no answer to compare.
+        let Some(part) = Step::from_fields(field(step), field(part))?.part() else {
+            continue;
+        };
+        let result = match field(status) {
+            "solved" => {
+                let mark = match field(verdict) {
+                    "correct" => " ✅",
+                    "incorrect" => " ❌",
+                    _ => "",
+                };
+                format!("{}{}", field(answer), mark)
+            }
+            "unsolved" => "not solved".to_string(),
+            "error" => format!("error: {}", field(error)),
+            other => other.replace('_', " "),
+        };
+        results.insert(part, result);
+    }
+    Some(results)
+}
+
+/// One line per part, saying how its result changed since the previous run.
+fn diff(previous: Option<&BTreeMap<u8, String>>, current: &BTreeMap<u8, String>) -> Vec<String> {
+    current
+        .iter()
+        .map(
+            |(part, result)| match previous.and_then(|previous| previous.get(part)) {
+                Some(before) if before == result => {
+                    format!("Part {}: {} (unchanged)", part, result)
+                }
+                Some(before) => format!("Part {}: {} -> {}", part, before, result),
+                None => format!("Part {}: {}", part, result),
+            },
+        )
+        .collect()
+}
+
+fn cargo(subcommand: &str) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.args([subcommand, "--quiet"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command
+}
+
+/// Builds and runs the day. Returns `None` if that failed, e.g. because it does not compile.
+fn run_day(year: u16, day: u8, options: &Options) -> Option<BTreeMap<u8, String>> {
+    let mut command = cargo("run");
+    command.args(["--bin", "solve", "--"]);
+    command.args([day.to_string(), "--year".into(), year.to_string()]);
+    command.args(["--format", "csv"]);
+    if let Some(part) = options.part {
+        command.args(["--part".to_string(), part.to_string()]);
+    }
+    if let Input::File(path) = &options.input {
+        command.arg("--input").arg(path);
+    }
+
+    // compile errors go straight to the terminal.
+    let output = command.stderr(Stdio::inherit()).output().ok()?;
+    results(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Runs the day's unit tests and its generated example tests. Returns the failures, if any.
+fn run_tests(year: u16, day: u8) -> Result<(), String> {
+    let output = cargo("test")
+        .args(["--lib", "--"])
+        .arg(format!("y{}::day{:02}::", year, day))
+        .arg(format!("examples::generated::y{}_day{:02}_", year, day))
+        .output()
+        .map_err(|e| format!("could not run cargo test: {}", e))?;
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let failures = stdout
+        .split_once("\nfailures:\n")
+        .map_or(stdout.as_ref(), |(_, failures)| failures);
+    Err(failures.trim().to_string())
+}
+
+/// Runs `day` whenever its files change, until the process is stopped.
+pub fn watch(year: u16, day: u8, options: &Options) -> ! {
+    let mut previous_snapshot = Snapshot::new();
+    let mut previous_results = None;
+    println!("Watching day {} of {}, stop with Ctrl-C.", day, year);
+
+    loop {
+        let paths = watched_paths(year, day, &options.input).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            vec![]
+        });
+        let current_snapshot = snapshot(&paths);
+        let changed = changed_paths(&previous_snapshot, &current_snapshot);
+        if changed.is_empty() {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+        let is_first_run = previous_snapshot.is_empty();
+        previous_snapshot = current_snapshot;
+
+        if !is_first_run {
+            let names: Vec<String> = changed
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            println!("{}Changed:{} {}", ANSI_BOLD, ANSI_RESET, names.join(", "));
+        }
+
+        match run_day(year, day, options) {
+            Some(results) => {
+                for line in diff(previous_results.as_ref(), &results) {
+                    println!("{}", line);
+                }
+                previous_results = Some(results);
+            }
+            None => println!("Could not run day {}, see above.", day),
+        }
+        match run_tests(year, day) {
+            Ok(()) => println!("Tests: ok"),
+            Err(failures) => println!("Tests: failed\n{}", failures),
+        }
+        println!(
+            "Watching {} files for changes...\n",
+            previous_snapshot.len()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_results() {
+        let output = "year,day,step,part,answer,elapsed_ns,contended,status,error,verdict\n\
+                      2022,10,parse,,,100,false,parsed,,unknown\n\
+                      2022,10,part,1,13140,100,false,solved,,correct\n\
+                      2022,10,part,2,\"##..\n##..\",100,false,solved,,unknown\n";
+        let parsed = results(output).unwrap();
+        assert_eq!(parsed[&1], "13140 ✅");
+        assert_eq!(parsed[&2], "##..\n##..");
+        assert_eq!(parsed.len(), 2);
+
+        let output = "year,day,step,part,answer,elapsed_ns,contended,status,error,verdict\n\
+                      2022,1,part,1,,0,false,error,\"could not open input file: \"\"01.txt\"\"\",unknown\n";
+        assert_eq!(
+            results(output).unwrap()[&1],
+            "error: could not open input file: \"01.txt\""
+        );
+        assert_eq!(results(""), None);
+    }
+
+    #[test]
+    fn test_diff() {
+        let first = BTreeMap::from([(1, "1 ✅".to_string()), (2, "not solved".to_string())]);
+        assert_eq!(diff(None, &first), ["Part 1: 1 ✅", "Part 2: not solved"]);
+
+        let second = BTreeMap::from([(1, "1 ✅".to_string()), (2, "42".to_string())]);
+        assert_eq!(
+            diff(Some(&first), &second),
+            ["Part 1: 1 ✅ (unchanged)", "Part 2: not solved -> 42"]
+        );
+    }
+
+    #[test]
+    fn test_changed_paths() {
+        let time = SystemTime::UNIX_EPOCH;
+        let later = time + Duration::from_secs(1);
+        let snapshot = |entries: &[(&str, Option<SystemTime>)]| -> Snapshot {
+            entries
+                .iter()
+                .map(|(path, modified)| (PathBuf::from(path), *modified))
+                .collect()
+        };
+
+        let previous = snapshot(&[("a", Some(time)), ("b", None), ("c", Some(time))]);
+        let current = snapshot(&[("a", Some(time)), ("b", Some(later)), ("d", None)]);
+        assert_eq!(
+            changed_paths(&previous, &current),
+            ["b", "d", "c"].map(PathBuf::from)
+        );
+        assert!(changed_paths(&current, &current).is_empty());
+    }
+}
@@ -0,0 +1,218 @@
+//! The solutions of 2022. Every day parses the puzzle input into its `Parsed` type with `parse`,
+//! and its `part_one` and `part_two` take that instead of the input:
+//!
+//! ```
+//! use advent_of_code::y2022::day04;
+//!
+//! let parsed = day04::parse("2-4,6-8\n2-8,3-7\n").unwrap();
+//! assert_eq!(parsed.len(), 2);
+//! assert_eq!(day04::part_one(&parsed), Ok(1));
+//! ```
+#![allow(clippy::type_complexity)]
+
+use crate::solution::{ParsedDay, Solution};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Every solved day of 2022, in order. Registered in `src/years.rs`.
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    &ParsedDay {
+        year: 2022,
+        day: 1,
+        parse: day01::parse,
+        part_one: day01::part_one,
+        part_two: day01::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 2,
+        parse: day02::parse,
+        part_one: day02::part_one,
+        part_two: day02::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 3,
+        parse: day03::parse,
+        part_one: day03::part_one,
+        part_two: day03::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 4,
+        parse: day04::parse,
+        part_one: day04::part_one,
+        part_two: day04::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 5,
+        parse: day05::parse,
+        part_one: day05::part_one,
+        part_two: day05::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 6,
+        parse: day06::parse,
+        part_one: day06::part_one,
+        part_two: day06::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 7,
+        parse: day07::parse,
+        part_one: day07::part_one,
+        part_two: day07::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 8,
+        parse: day08::parse,
+        part_one: day08::part_one,
+        part_two: day08::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 9,
+        parse: day09::parse,
+        part_one: day09::part_one,
+        part_two: day09::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 10,
+        parse: day10::parse,
+        part_one: day10::part_one,
+        part_two: day10::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 11,
+        parse: day11::parse,
+        part_one: day11::part_one,
+        part_two: day11::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 12,
+        parse: day12::parse,
+        part_one: day12::part_one,
+        part_two: day12::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 13,
+        parse: day13::parse,
+        part_one: day13::part_one,
+        part_two: day13::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 14,
+        parse: day14::parse,
+        part_one: day14::part_one,
+        part_two: day14::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 15,
+        parse: day15::parse,
+        part_one: day15::part_one,
+        part_two: day15::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 16,
+        parse: day16::parse,
+        part_one: day16::part_one,
+        part_two: day16::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 17,
+        parse: day17::parse,
+        part_one: day17::part_one,
+        part_two: day17::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 18,
+        parse: day18::parse,
+        part_one: day18::part_one,
+        part_two: day18::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 19,
+        parse: day19::parse,
+        part_one: day19::part_one,
+        part_two: day19::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 20,
+        parse: day20::parse,
+        part_one: day20::part_one,
+        part_two: day20::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 21,
+        parse: day21::parse,
+        part_one: day21::part_one,
+        part_two: day21::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 22,
+        parse: day22::parse,
+        part_one: day22::part_one,
+        part_two: day22::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 23,
+        parse: day23::parse,
+        part_one: day23::part_one,
+        part_two: day23::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 24,
+        parse: day24::parse,
+        part_one: day24::part_one,
+        part_two: day24::part_two,
+    },
+    &ParsedDay {
+        year: 2022,
+        day: 25,
+        parse: day25::parse,
+        part_one: day25::part_one,
+        part_two: day25::part_two,
+    },
+];
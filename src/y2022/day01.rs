@@ -0,0 +1,69 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::error::{parse_all, SolveError};
+use itertools::Itertools;
+use nom::{
+    character::complete::{digit1, line_ending},
+    combinator::map_res,
+    multi::{separated_list0, separated_list1},
+    sequence::pair,
+    IResult,
+};
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
+    separated_list0(
+        pair(line_ending, line_ending),
+        separated_list1(line_ending, map_res(digit1, str::parse::<u32>)),
+    )(input)
+}
+
+/// The calories of the items every elf carries.
+pub type Parsed = Vec<Vec<u32>>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+fn solve(elves: &Parsed, max_len: usize) -> Option<u32> {
+    elves
+        .iter()
+        .map(|items| items.iter().sum::<u32>())
+        // Record the `max_len` largest sums
+        .fold(BinaryHeap::<Reverse<u32>>::new(), |mut min_heap, sum| {
+            if min_heap.len() < max_len {
+                min_heap.push(Reverse(sum));
+            } else if min_heap.peek().unwrap().0 < sum {
+                min_heap.pop();
+                min_heap.push(Reverse(sum));
+            }
+            min_heap
+        })
+        .into_iter()
+        .map(|rev| rev.0)
+        .sum1()
+}
+
+pub fn part_one(elves: &Parsed) -> Option<u32> {
+    solve(elves, 1)
+}
+
+pub fn part_two(elves: &Parsed) -> Option<u32> {
+    solve(elves, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 1);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(24000));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 1);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(45000));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::error::{parse_all, SolveError};
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{line_ending, one_of},
+    multi::separated_list0,
+    sequence::separated_pair,
+    IResult,
+};
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(char, char)>> {
+    separated_list0(
+        line_ending,
+        separated_pair(one_of("ABC"), tag(" "), one_of("XYZ")),
+    )(input)
+}
+
+/// Every round as the opponent's shape, `A` to `C`, and the second column, `X` to `Z`.
+pub type Parsed = Vec<(char, char)>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+pub fn part_one(rounds: &Parsed) -> Option<u32> {
+    rounds
+        .iter()
+        .map(|&(c1, c2)| {
+            let shape_score = match c2 {
+                'X' => 1, // rock
+                'Y' => 2, // paper
+                'Z' => 3, // scissors
+                _ => unreachable!(),
+            };
+            let outcome_score = match (c1, c2) {
+                ('A', 'Z') | ('B', 'X') | ('C', 'Y') => 0, // loss
+                ('A', 'X') | ('B', 'Y') | ('C', 'Z') => 3, // draw
+                ('A', 'Y') | ('B', 'Z') | ('C', 'X') => 6, // win
+                _ => unreachable!(),
+            };
+            shape_score + outcome_score
+        })
+        .sum1()
+}
+
+pub fn part_two(rounds: &Parsed) -> Option<u32> {
+    rounds
+        .iter()
+        .map(|&(c1, c2)| {
+            let shape_score = match (c1, c2) {
+                ('A', 'Y') | ('B', 'X') | ('C', 'Z') => 1, // rock
+                ('A', 'Z') | ('B', 'Y') | ('C', 'X') => 2, // paper
+                ('A', 'X') | ('B', 'Z') | ('C', 'Y') => 3, // scissors
+                _ => unreachable!(),
+            };
+            let outcome_score = match c2 {
+                'X' => 0, // loss
+                'Y' => 3, // draw
+                'Z' => 6, // win
+                _ => unreachable!(),
+            };
+            shape_score + outcome_score
+        })
+        .sum1()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 2);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(15));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 2);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(12));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("A Y\nB W\n"),
+            Err(SolveError::TrailingInput {
+                line: 2,
+                column: 1,
+                remaining: "B W".to_string()
+            })
+        );
+    }
+}
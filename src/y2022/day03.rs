@@ -0,0 +1,97 @@
+use crate::error::{parse_all, SolveError};
+use itertools::Itertools;
+use nom::{
+    character::complete::{line_ending, satisfy},
+    combinator::map,
+    multi::{many1, separated_list0},
+    IResult,
+};
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Vec<u32>>> {
+    separated_list0(line_ending, many1(parse_item))(input)
+}
+
+fn parse_item(input: &str) -> IResult<&str, u32> {
+    map(satisfy(|c| c.is_ascii_alphabetic()), |c| match c {
+        'a'..='z' => (c as u32) - ('a' as u32) + 1,
+        _ => (c as u32) - ('A' as u32) + 27,
+    })(input)
+}
+
+/// The priorities of the items in every rucksack.
+pub type Parsed = Vec<Vec<u32>>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+pub fn part_one(rucksacks: &Parsed) -> Result<u32, SolveError> {
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(index, items)| {
+            let (left_items, right_items) = items.split_at(items.len() / 2);
+            left_items
+                .iter()
+                .copied()
+                .find(|item| right_items.contains(item))
+                .ok_or_else(|| {
+                    SolveError::Invalid(format!(
+                        "rucksack {} has no item in both compartments",
+                        index + 1
+                    ))
+                })
+        })
+        .sum()
+}
+
+pub fn part_two(rucksacks: &Parsed) -> Result<u32, SolveError> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(SolveError::Invalid(format!(
+            "{} rucksacks cannot be split into groups of three",
+            rucksacks.len()
+        )));
+    }
+
+    rucksacks
+        .iter()
+        .tuples()
+        .enumerate()
+        .map(|(index, (items1, items2, items3))| {
+            items1
+                .iter()
+                .copied()
+                .find(|item| items2.contains(item) && items3.contains(item))
+                .ok_or_else(|| SolveError::Invalid(format!("group {} has no badge", index + 1)))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 3);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(157));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 3);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(70));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(parse("abc\nab1\n").is_err());
+        assert_eq!(
+            part_one(&parse("abAB\n").unwrap()),
+            Err(SolveError::Invalid(
+                "rucksack 1 has no item in both compartments".to_string()
+            ))
+        );
+        assert!(part_two(&parse("a\na\n").unwrap()).is_err());
+    }
+}
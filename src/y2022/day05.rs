@@ -0,0 +1,116 @@
+use crate::error::{parse_all, SolveError};
+use crate::helpers::get_pair_mut;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{anychar, digit1, line_ending, multispace1},
+    combinator::{map, map_res, value},
+    multi::{many1_count, separated_list0, separated_list1},
+    sequence::{delimited, preceded, separated_pair, tuple},
+    IResult,
+};
+
+fn parse_input(input: &str) -> IResult<&str, (Vec<Vec<char>>, Vec<(u32, u32, u32)>)> {
+    separated_pair(parse_stacks, multispace1, parse_moves)(input)
+}
+
+/// The crates of every stack from the bottom up, and every move as the number of crates to move
+/// and the 1-based stacks to move them from and to.
+pub type Parsed = (Vec<Vec<char>>, Vec<(u32, u32, u32)>);
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+fn parse_stacks(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    let (input, rows) = separated_list0(multispace1, parse_stack_row)(input)?;
+    let (input, num_stacks) = many1_count(tuple((multispace1, digit1)))(input)?;
+
+    let mut stacks = vec![vec![]; num_stacks];
+    for row in rows.into_iter().rev() {
+        for (index, c) in row.into_iter().enumerate() {
+            if let Some(c) = c {
+                stacks[index].push(c);
+            }
+        }
+    }
+
+    Ok((input, stacks))
+}
+
+fn parse_stack_row(input: &str) -> IResult<&str, Vec<Option<char>>> {
+    separated_list1(
+        tag(" "),
+        alt((
+            value(None, tag("   ")),
+            map(delimited(tag("["), anychar, tag("]")), Some),
+        )),
+    )(input)
+}
+
+fn parse_moves(input: &str) -> IResult<&str, Vec<(u32, u32, u32)>> {
+    separated_list0(
+        line_ending,
+        tuple((
+            preceded(tag("move "), map_res(digit1, str::parse::<u32>)),
+            preceded(tag(" from "), map_res(digit1, str::parse::<u32>)),
+            preceded(tag(" to "), map_res(digit1, str::parse::<u32>)),
+        )),
+    )(input)
+}
+
+pub fn part_one((stacks, moves): &Parsed) -> Result<String, SolveError> {
+    let mut stacks = stacks.clone();
+
+    for &(amount, from, to) in moves {
+        let (from_stack, to_stack) =
+            get_pair_mut(&mut stacks, ((from - 1) as usize, (to - 1) as usize));
+
+        for _ in 0..amount {
+            to_stack.push(from_stack.pop().unwrap());
+        }
+    }
+
+    let result: String = stacks
+        .into_iter()
+        .map(|stack| stack.last().unwrap().to_owned())
+        .collect();
+    Ok(result)
+}
+
+pub fn part_two((stacks, moves): &Parsed) -> Result<String, SolveError> {
+    let mut stacks = stacks.clone();
+
+    for &(amount, from, to) in moves {
+        let (from_stack, to_stack) =
+            get_pair_mut(&mut stacks, ((from - 1) as usize, (to - 1) as usize));
+
+        assert!(from_stack.len() >= amount as usize);
+
+        let start = from_stack.len() - ((amount) as usize);
+        to_stack.extend(from_stack.drain(start..));
+    }
+
+    let result: String = stacks
+        .into_iter()
+        .map(|stack| stack.last().unwrap().to_owned())
+        .collect();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 5);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 5);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok("MCD".to_string()));
+    }
+}
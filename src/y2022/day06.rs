@@ -0,0 +1,86 @@
+use crate::error::{parse_all, SolveError};
+use nom::{character::complete::satisfy, combinator::map, multi::many0, IResult};
+
+fn parse_input(input: &str) -> IResult<&str, Vec<usize>> {
+    many0(map(satisfy(|c| c.is_ascii_lowercase()), |c| {
+        (c as usize) - ('a' as usize)
+    }))(input)
+}
+
+/// The datastream as character indices, 'a'=0, 'b'=1, ..., 'z'=25.
+pub type Parsed = Vec<usize>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+fn solve(input: &Parsed, marker_len: usize) -> Option<usize> {
+    if input.len() < marker_len {
+        return None;
+    }
+
+    // Map from character index to the number of occurences in the last `marker_len` characters.
+    let mut counts = [0; 26];
+    // Number of duplicates in the last `marker_len` characters.
+    let mut duplicates = 0;
+
+    // Initalize `counts` and `duplicates`
+    for &idx in &input[0..marker_len] {
+        counts[idx] += 1;
+        if counts[idx] >= 2 {
+            duplicates += 1;
+        }
+    }
+
+    // Process remainder of the input stream
+    for pos in marker_len..input.len() {
+        if duplicates == 0 {
+            return Some(pos);
+        }
+
+        let old_idx = input[pos - marker_len];
+        counts[old_idx] -= 1;
+        if counts[old_idx] >= 1 {
+            duplicates -= 1;
+        }
+
+        let new_idx = input[pos];
+        counts[new_idx] += 1;
+        if counts[new_idx] >= 2 {
+            duplicates += 1;
+        }
+    }
+
+    None
+}
+
+pub fn part_one(datastream: &Parsed) -> Option<usize> {
+    solve(datastream, 4)
+}
+
+pub fn part_two(datastream: &Parsed) -> Option<usize> {
+    solve(datastream, 14)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 6);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(7));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 6);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(19));
+    }
+
+    #[test]
+    fn test_short_datastream() {
+        assert!(parse("ab1\n").is_err());
+        assert_eq!(part_two(&parse("abcd\n").unwrap()), None);
+    }
+}
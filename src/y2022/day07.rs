@@ -0,0 +1,101 @@
+use crate::error::SolveError;
+use nom::{character::complete::digit1, combinator::map_res, IResult};
+
+fn parse_size_prefix(input: &str) -> IResult<&str, usize> {
+    map_res(digit1, str::parse::<usize>)(input)
+}
+
+/// The sizes of all directories, the root last.
+pub type Parsed = Vec<usize>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    // ASSUMPTION: We never revisit a directory after leaving it.
+
+    let mut sizes = vec![];
+    let mut stack = vec![0];
+
+    for (line_idx, line) in input.lines().enumerate() {
+        if let Some(arg) = line.strip_prefix("$ cd ") {
+            // `cd` command
+            match arg {
+                "/" => {
+                    while stack.len() > 1 {
+                        let size = stack.pop().unwrap();
+                        sizes.push(size);
+                        *stack.last_mut().unwrap() += size;
+                    }
+                }
+                ".." => {
+                    if stack.len() == 1 {
+                        return Err(SolveError::Invalid(format!(
+                            "line {} leaves the root directory",
+                            line_idx + 1
+                        )));
+                    }
+                    let size = stack.pop().unwrap();
+                    sizes.push(size);
+                    *stack.last_mut().unwrap() += size;
+                }
+                _ => {
+                    stack.push(0);
+                }
+            }
+        } else if let Ok((_, size)) = parse_size_prefix(line) {
+            // `ls` file output
+            *stack.last_mut().unwrap() += size;
+        }
+        // `ls` command and `ls` dir output are ignored
+    }
+
+    // Clean up sizes left on the stack
+    while stack.len() > 1 {
+        let size = stack.pop().unwrap();
+        sizes.push(size);
+        *stack.last_mut().unwrap() += size;
+    }
+    sizes.push(stack[0]);
+
+    Ok(sizes)
+}
+
+pub fn part_one(sizes: &Parsed) -> Option<usize> {
+    let result = sizes.iter().filter(|size| **size <= 100_000).sum();
+    Some(result)
+}
+
+pub fn part_two(sizes: &Parsed) -> Option<usize> {
+    let root_size = sizes.last().unwrap();
+    let target_size = root_size - (70_000_000 - 30_000_000);
+    sizes
+        .iter()
+        .copied()
+        .filter(|size| *size >= target_size)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 7);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(95437));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 7);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(24933642));
+    }
+
+    #[test]
+    fn test_leave_root() {
+        assert_eq!(
+            parse("$ cd /\n$ cd ..\n"),
+            Err(SolveError::Invalid(
+                "line 2 leaves the root directory".to_string()
+            ))
+        );
+    }
+}
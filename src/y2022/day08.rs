@@ -0,0 +1,82 @@
+use itertools::FoldWhile::{Continue, Done};
+use itertools::Itertools;
+
+use crate::error::SolveError;
+use crate::helpers::grid::Grid;
+use crate::helpers::point::Direction;
+
+/// The height of every tree.
+pub type Parsed = Grid<u32>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    Grid::parse(input, |c| c.to_digit(10))
+}
+
+pub fn part_one(trees: &Parsed) -> Option<usize> {
+    // a tree is visible if all trees between it and an edge are shorter, so walk inward from
+    // every edge and mark each tree that is taller than all trees before it.
+    let mut visible = trees.map(|_| false);
+    for direction in Direction::ALL {
+        let edge = trees
+            .positions()
+            .filter(|&pos| trees.step(pos, direction.opposite().vector()).is_none());
+        for start in edge {
+            let mut max_height = None;
+            for pos in std::iter::once(start).chain(trees.ray(start, direction.vector())) {
+                if max_height.is_none_or(|max_height| trees[pos] > max_height) {
+                    visible[pos] = true;
+                    max_height = Some(trees[pos]);
+                }
+                // no tree further in can be taller
+                if max_height == Some(9) {
+                    break;
+                }
+            }
+        }
+    }
+
+    Some(visible.iter().filter(|&(_, &visible)| visible).count())
+}
+
+pub fn part_two(trees: &Parsed) -> Option<usize> {
+    let mut max_scenic_score = 0;
+
+    for (pos, &tree_height) in trees.iter() {
+        let scenic_score: usize = Direction::ALL
+            .into_iter()
+            .map(|direction| {
+                trees
+                    .ray(pos, direction.vector())
+                    .fold_while(0, |view_distance, other_pos| {
+                        if trees[other_pos] >= tree_height {
+                            Done(view_distance + 1)
+                        } else {
+                            Continue(view_distance + 1)
+                        }
+                    })
+                    .into_inner()
+            })
+            .product();
+
+        max_scenic_score = max_scenic_score.max(scenic_score);
+    }
+
+    Some(max_scenic_score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 8);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 8);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(8));
+    }
+}
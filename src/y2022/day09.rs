@@ -0,0 +1,88 @@
+use std::collections::HashSet;
+
+use crate::error::{parse_all, SolveError};
+use crate::helpers::get_pair_mut;
+use crate::helpers::point::{Direction, Point2};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{anychar, digit1, line_ending},
+    combinator::{map_opt, map_res},
+    multi::separated_list0,
+    sequence::separated_pair,
+    IResult,
+};
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(Direction, u32)>> {
+    separated_list0(line_ending, parse_line)(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, (Direction, u32)> {
+    separated_pair(
+        map_opt(anychar, Direction::from_char),
+        tag(" "),
+        map_res(digit1, str::parse::<u32>),
+    )(input)
+}
+
+/// Every motion of the head as its direction and number of steps.
+pub type Parsed = Vec<(Direction, u32)>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+fn solve(motions: &Parsed, rope_len: usize) -> Option<usize> {
+    let mut visited = HashSet::<Point2<i32>>::new();
+    let mut rope = vec![Point2::new(0, 0); rope_len];
+
+    for &(dir, steps) in motions {
+        for _step in 0..steps {
+            rope[0] += dir.vector();
+
+            for i in 1..rope_len {
+                let (h_pos, t_pos) = get_pair_mut(&mut rope, (i - 1, i));
+
+                if h_pos.chebyshev(*t_pos) >= 2 {
+                    *t_pos += (*h_pos - *t_pos).map(i32::signum);
+                }
+            }
+
+            visited.insert(rope[rope_len - 1]);
+        }
+    }
+
+    Some(visited.len())
+}
+
+pub fn part_one(motions: &Parsed) -> Option<usize> {
+    solve(motions, 2)
+}
+
+pub fn part_two(motions: &Parsed) -> Option<usize> {
+    solve(motions, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 9);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 9);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(1));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("R 4\nX 2\n").unwrap_err().to_string(),
+            "unparsed input at line 2, column 1: \"X 2\""
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use crate::error::{parse_all, SolveError};
+use crate::helpers::parse_signed_int;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::line_ending,
+    combinator::{map, value},
+    multi::separated_list0,
+    sequence::preceded,
+    IResult,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    Noop,
+    AddX(i32),
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    alt((
+        value(Instruction::Noop, tag("noop")),
+        map(preceded(tag("addx "), parse_signed_int), Instruction::AddX),
+    ))(input)
+}
+
+/// The program, one instruction per line.
+pub type Parsed = Vec<Instruction>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, separated_list0(line_ending, parse_instruction))
+}
+
+pub fn part_one(instructions: &Parsed) -> Option<i32> {
+    let mut cycle = 0;
+    let mut x = 1;
+
+    let mut total_signal_strength = 0;
+
+    for &instruction in instructions {
+        let duration = match instruction {
+            Instruction::Noop => 1,
+            Instruction::AddX(_) => 2,
+        };
+
+        for _ in 0..duration {
+            cycle += 1;
+
+            if cycle % 40 == 20 {
+                total_signal_strength += cycle * x;
+            }
+        }
+
+        match instruction {
+            Instruction::Noop => {}
+            Instruction::AddX(v) => x += v,
+        }
+    }
+
+    Some(total_signal_strength)
+}
+
+pub fn part_two(instructions: &Parsed) -> Option<String> {
+    let mut cycle = 0;
+    let mut sprite_x = 1;
+
+    let mut screen = String::new();
+
+    for &instruction in instructions {
+        let duration = match instruction {
+            Instruction::Noop => 1,
+            Instruction::AddX(_) => 2,
+        };
+
+        for _ in 0..duration {
+            let screen_x = cycle % 40;
+            if sprite_x - 1 <= screen_x && screen_x <= sprite_x + 1 {
+                screen.push('#');
+            } else {
+                screen.push('.');
+            }
+
+            cycle += 1;
+
+            if cycle != 0 && cycle % 40 == 0 {
+                screen.push('\n');
+            }
+        }
+
+        match instruction {
+            Instruction::Noop => {}
+            Instruction::AddX(v) => sprite_x += v,
+        }
+    }
+
+    Some(screen)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 10);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(13140));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 10);
+        assert_eq!(
+            part_two(&parse(&input).unwrap()),
+            Some(
+                "##..##..##..##..##..##..##..##..##..##..\n\
+                 ###...###...###...###...###...###...###.\n\
+                 ####....####....####....####....####....\n\
+                 #####.....#####.....#####.....#####.....\n\
+                 ######......######......######......####\n\
+                 #######.......#######.......#######.....\n"
+                    .to_string()
+            )
+        );
+    }
+}
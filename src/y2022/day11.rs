@@ -0,0 +1,149 @@
+use crate::error::{parse_all, SolveError};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending},
+    combinator::{map, map_res, value},
+    multi::{count, separated_list0},
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+
+#[derive(Debug)]
+pub struct Monkey {
+    pub starting_items: Vec<usize>,
+    pub operation: Operation,
+    pub test_divisible_by: usize,
+    pub if_true: usize,
+    pub if_false: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Operation {
+    Add(usize),
+    Mult(usize),
+    Square,
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Monkey>> {
+    separated_list0(count(line_ending, 2), parse_monkey)(input)
+}
+
+/// The monkeys in the order of their numbers, which the throw targets refer to.
+pub type Parsed = Vec<Monkey>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
+    let (input, _) = tuple((tag("Monkey "), digit1, tag(":"), line_ending))(input)?;
+    let (input, starting_items) = delimited(
+        tag("  Starting items: "),
+        separated_list0(tag(", "), map_res(digit1, str::parse::<usize>)),
+        line_ending,
+    )(input)?;
+    let (input, operation) = delimited(
+        tag("  Operation: new = old "),
+        alt((
+            map(
+                preceded(tag("+ "), map_res(digit1, str::parse::<usize>)),
+                Operation::Add,
+            ),
+            map(
+                preceded(tag("* "), map_res(digit1, str::parse::<usize>)),
+                Operation::Mult,
+            ),
+            value(Operation::Square, tag("* old")),
+        )),
+        line_ending,
+    )(input)?;
+    let (input, test_divisible_by) = delimited(
+        tag("  Test: divisible by "),
+        map_res(digit1, str::parse::<usize>),
+        line_ending,
+    )(input)?;
+    let (input, if_true) = delimited(
+        tag("    If true: throw to monkey "),
+        map_res(digit1, str::parse::<usize>),
+        line_ending,
+    )(input)?;
+    let (input, if_false) = preceded(
+        tag("    If false: throw to monkey "),
+        map_res(digit1, str::parse::<usize>),
+    )(input)?;
+
+    Ok((
+        input,
+        Monkey {
+            starting_items,
+            operation,
+            test_divisible_by,
+            if_true,
+            if_false,
+        },
+    ))
+}
+
+fn solve(monkeys: &[Monkey], num_rounds: usize, divisor: usize) -> Result<usize, SolveError> {
+    let mut monkeys_items = monkeys
+        .iter()
+        .map(|m| m.starting_items.clone())
+        .collect::<Vec<_>>();
+
+    let modulo: usize = monkeys.iter().map(|m| m.test_divisible_by).product();
+
+    let mut inspections = vec![0; monkeys.len()];
+
+    for _round in 0..num_rounds {
+        for idx in 0..monkeys.len() {
+            let monkey = &monkeys[idx];
+            let monkey_items = std::mem::take(&mut monkeys_items[idx]);
+
+            for item in monkey_items {
+                inspections[idx] += 1;
+
+                let item = match monkey.operation {
+                    Operation::Add(x) => item + x,
+                    Operation::Mult(x) => item * x,
+                    Operation::Square => item * item,
+                };
+                let item = (item / divisor) % modulo;
+
+                let target_idx = match item.is_multiple_of(monkey.test_divisible_by) {
+                    true => monkey.if_true,
+                    false => monkey.if_false,
+                };
+                monkeys_items[target_idx].push(item);
+            }
+        }
+    }
+
+    inspections.sort_by(|a, b| b.cmp(a));
+    Ok(inspections[0] * inspections[1])
+}
+
+pub fn part_one(monkeys: &Parsed) -> Result<usize, SolveError> {
+    solve(monkeys, 20, 3)
+}
+
+pub fn part_two(monkeys: &Parsed) -> Result<usize, SolveError> {
+    solve(monkeys, 10000, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 11);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(10605));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 11);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(2713310158));
+    }
+}
@@ -0,0 +1,62 @@
+use crate::error::SolveError;
+use crate::helpers::grid::{Grid, Pos};
+use crate::helpers::search;
+
+/// The heights of the grid, the start and the end point.
+pub type Parsed = (Grid<u8>, Pos, Pos);
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    let map = Grid::parse(input, |c| matches!(c, 'S' | 'E' | 'a'..='z').then_some(c))?;
+    let find = |marker| {
+        map.position(|&c| c == marker)
+            .ok_or_else(|| SolveError::Invalid(format!("no {} on the map", marker)))
+    };
+    let (start_point, end_point) = (find('S')?, find('E')?);
+
+    let grid = map.map(|&c| match c {
+        'S' => 0,
+        'E' => 25,
+        c => (c as u8) - b'a',
+    });
+    Ok((grid, start_point, end_point))
+}
+
+/// The fewest steps from any of `starts` to the end, climbing at most one higher per step.
+fn climb(parsed: &Parsed, starts: impl IntoIterator<Item = Pos>) -> Option<usize> {
+    let &(ref grid, _, end_point) = parsed;
+    let found = search::bfs(
+        starts,
+        |&point| {
+            grid.neighbors4(point)
+                .filter(move |&next| grid[next] <= grid[point] + 1)
+        },
+        |&point| point == end_point,
+    )?;
+    Some(found.cost())
+}
+
+pub fn part_one(parsed: &Parsed) -> Option<usize> {
+    climb(parsed, [parsed.1])
+}
+
+pub fn part_two(parsed: &Parsed) -> Option<usize> {
+    let grid = &parsed.0;
+    climb(parsed, grid.positions().filter(|&point| grid[point] == 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 12);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 12);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(29));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::error::{parse_all, SolveError};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending},
+    combinator::{map, map_res},
+    multi::{count, separated_list0},
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PacketValue {
+    Integer(u32),
+    List(Vec<PacketValue>),
+}
+
+impl Ord for PacketValue {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (PacketValue::Integer(left), PacketValue::Integer(right)) => left.cmp(right),
+            (PacketValue::List(left), PacketValue::List(right)) => {
+                let mut left_it = left.iter();
+                let mut right_it = right.iter();
+
+                loop {
+                    match (left_it.next(), right_it.next()) {
+                        (Some(left), Some(right)) => {
+                            let cmp = left.cmp(right);
+                            if !cmp.is_eq() {
+                                break cmp;
+                            }
+                        }
+                        (None, None) => {
+                            break std::cmp::Ordering::Equal;
+                        }
+                        (None, Some(_)) => {
+                            break std::cmp::Ordering::Less;
+                        }
+                        (Some(_), None) => {
+                            break std::cmp::Ordering::Greater;
+                        }
+                    }
+                }
+            }
+            (PacketValue::Integer(_), PacketValue::List(_)) => {
+                PacketValue::List(vec![self.clone()]).cmp(other)
+            }
+            (PacketValue::List(_), PacketValue::Integer(_)) => {
+                self.cmp(&PacketValue::List(vec![other.clone()]))
+            }
+        }
+    }
+}
+
+impl PartialOrd for PacketValue {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(PacketValue, PacketValue)>> {
+    separated_list0(
+        count(line_ending, 2),
+        separated_pair(parse_packet_value, line_ending, parse_packet_value),
+    )(input)
+}
+
+/// Every pair of packets, in the order of their indices.
+pub type Parsed = Vec<(PacketValue, PacketValue)>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+fn parse_packet_value(input: &str) -> IResult<&str, PacketValue> {
+    alt((
+        map(map_res(digit1, str::parse::<u32>), PacketValue::Integer),
+        map(
+            delimited(
+                tag("["),
+                separated_list0(tag(","), parse_packet_value),
+                tag("]"),
+            ),
+            PacketValue::List,
+        ),
+    ))(input)
+}
+
+pub fn part_one(packet_pairs: &Parsed) -> Result<usize, SolveError> {
+    let mut result = 0;
+
+    for (index, (left, right)) in packet_pairs.iter().enumerate() {
+        if left < right {
+            result += index + 1;
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn part_two(packet_pairs: &Parsed) -> Result<usize, SolveError> {
+    let mut packets: Vec<PacketValue> = packet_pairs
+        .iter()
+        .flat_map(|(left, right)| [left.clone(), right.clone()])
+        .collect();
+
+    let divider_packet1 = PacketValue::List(vec![PacketValue::List(vec![PacketValue::Integer(2)])]);
+    let divider_packet2 = PacketValue::List(vec![PacketValue::List(vec![PacketValue::Integer(6)])]);
+    packets.push(divider_packet1.clone());
+    packets.push(divider_packet2.clone());
+
+    packets.sort();
+
+    let mut result = 1;
+    for (index, packet) in packets.into_iter().enumerate() {
+        if packet == divider_packet1 || packet == divider_packet2 {
+            result *= index + 1;
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 13);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 13);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(140));
+    }
+}
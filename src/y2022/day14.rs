@@ -0,0 +1,134 @@
+use crate::error::{parse_all, SolveError};
+use crate::helpers::grid::Grid;
+use crate::helpers::point::Point2;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending},
+    combinator::{map, map_res},
+    multi::separated_list0,
+    sequence::separated_pair,
+    IResult,
+};
+
+fn parse_input(input: &str) -> IResult<&str, Parsed> {
+    separated_list0(
+        line_ending,
+        separated_list0(
+            tag(" -> "),
+            map(
+                separated_pair(
+                    map_res(digit1, str::parse),
+                    tag(","),
+                    map_res(digit1, str::parse),
+                ),
+                Point2::from,
+            ),
+        ),
+    )(input)
+}
+
+/// Every rock path as the points along it, joined by straight lines.
+pub type Parsed = Vec<Vec<Point2<usize>>>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+fn solve(traces: &Parsed) -> Result<(u32, u32), SolveError> {
+    let y_abyss = traces
+        .iter()
+        .flatten()
+        .map(|point| point.y)
+        .max()
+        .unwrap_or(0);
+    let y_floor = y_abyss + 2;
+    if y_floor > 500 {
+        return Err(SolveError::Invalid("the cave is too deep".to_string()));
+    }
+
+    // Sand spreads by at most one column per row, so it stays within y_floor columns of x = 500
+    let x_max = traces
+        .iter()
+        .flatten()
+        .map(|point| point.x)
+        .max()
+        .unwrap_or(0);
+    let mut grid = Grid::new(x_max.max(500 + y_floor) + 1, y_floor + 1, false);
+
+    for trace in traces {
+        for points in trace.windows(2) {
+            let p1 = points[0];
+            let p2 = points[1];
+
+            for x in p1.x.min(p2.x)..=p1.x.max(p2.x) {
+                for y in p1.y.min(p2.y)..=p1.y.max(p2.y) {
+                    grid[Point2::new(x, y)] = true;
+                }
+            }
+        }
+    }
+
+    let mut result_part1 = None;
+
+    for unit in 0.. {
+        let mut x = 500;
+        let mut y = 0;
+        loop {
+            if !grid[Point2::new(x, y + 1)] {
+                y += 1;
+            } else if !grid[Point2::new(x - 1, y + 1)] {
+                x -= 1;
+                y += 1;
+            } else if !grid[Point2::new(x + 1, y + 1)] {
+                x += 1;
+                y += 1;
+            } else {
+                if y == 0 && x == 500 {
+                    let part_one = result_part1.ok_or_else(|| {
+                        SolveError::Invalid("no sand fell into the abyss".to_string())
+                    })?;
+                    return Ok((part_one, unit + 1));
+                }
+
+                grid[Point2::new(x, y)] = true;
+                break;
+            }
+
+            if result_part1.is_none() && y >= y_abyss {
+                result_part1 = Some(unit);
+            }
+
+            if y == y_floor - 1 {
+                grid[Point2::new(x, y)] = true;
+                break;
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn part_one(traces: &Parsed) -> Result<u32, SolveError> {
+    solve(traces).map(|(part_one, _)| part_one)
+}
+
+pub fn part_two(traces: &Parsed) -> Result<u32, SolveError> {
+    solve(traces).map(|(_, part_two)| part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 14);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(24));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 14);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(93));
+    }
+}
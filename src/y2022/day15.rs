@@ -0,0 +1,130 @@
+use crate::error::{parse_all, SolveError};
+use crate::helpers::parse_signed_int;
+use crate::helpers::point::Point2;
+use nom::{
+    bytes::complete::tag,
+    character::complete::line_ending,
+    combinator::map,
+    multi::separated_list0,
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(Point2<i32>, Point2<i32>)>> {
+    separated_list0(
+        line_ending,
+        separated_pair(
+            preceded(tag("Sensor at "), parse_coord),
+            tag(": "),
+            preceded(tag("closest beacon is at "), parse_coord),
+        ),
+    )(input)
+}
+
+/// Every sensor with the beacon closest to it.
+pub type Parsed = Vec<(Point2<i32>, Point2<i32>)>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+fn parse_coord(input: &str) -> IResult<&str, Point2<i32>> {
+    map(
+        separated_pair(
+            preceded(tag("x="), parse_signed_int),
+            tag(", "),
+            preceded(tag("y="), parse_signed_int),
+        ),
+        Point2::from,
+    )(input)
+}
+
+fn solve_part_one(input: &Parsed, target_y: i32) -> Result<u32, SolveError> {
+    let mut x_intervals = Vec::with_capacity(input.len());
+
+    for &(sensor, closest_beacon) in input {
+        let diamond_size = sensor.manhattan(closest_beacon);
+        let interval_size = (diamond_size - (target_y - sensor.y).abs()).abs();
+
+        x_intervals.push((sensor.x - interval_size, sensor.x + interval_size));
+    }
+
+    x_intervals.sort();
+
+    let mut x_cur = x_intervals[0].0;
+    let mut result = 0;
+
+    for (x_min, x_max) in x_intervals {
+        let x_min = x_min.max(x_cur);
+
+        if x_min <= x_max {
+            result += x_min.abs_diff(x_max);
+        }
+
+        x_cur = x_cur.max(x_max);
+    }
+
+    Ok(result)
+}
+
+pub fn part_one(input: &Parsed) -> Result<u32, SolveError> {
+    solve_part_one(input, 2000000)
+}
+
+pub fn solve_part_two(input: &Parsed, size: u32) -> Result<u64, SolveError> {
+    let size = size as i32;
+    let sensors: Vec<(Point2<i32>, i32)> = input
+        .iter()
+        .map(|&(sensor, closest_beacon)| (sensor, sensor.manhattan(closest_beacon)))
+        .collect();
+
+    for y in 0..=size {
+        let mut x_intervals = vec![];
+
+        for &(sensor, range) in &sensors {
+            let y_diff = (y - sensor.y).abs();
+            if y_diff <= range {
+                let interval_size = range - y_diff;
+                x_intervals.push((
+                    (sensor.x - interval_size).max(0),
+                    (sensor.x + interval_size).min(size),
+                ));
+            }
+        }
+
+        x_intervals.sort();
+
+        let mut x = 0;
+        for (x_min, x_max) in x_intervals {
+            if x < x_min {
+                return Ok((x as u64) * 4000000 + y as u64);
+            }
+            x = x.max(x_max + 1);
+        }
+    }
+
+    Err(SolveError::Invalid(
+        "no position left for the distress beacon".to_string(),
+    ))
+}
+
+pub fn part_two(input: &Parsed) -> Result<u64, SolveError> {
+    solve_part_two(input, 4000000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 15);
+        assert_eq!(solve_part_one(&parse(&input).unwrap(), 10), Ok(26));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 15);
+        assert_eq!(solve_part_two(&parse(&input).unwrap(), 20), Ok(56000011));
+    }
+}
@@ -0,0 +1,246 @@
+use crate::error::{parse_all, SolveError};
+use crate::helpers::bnb::{self, Goal, Problem, Visited};
+use hashbrown::HashMap;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, digit1, line_ending},
+    combinator::{map, map_res},
+    multi::separated_list0,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+fn parse_input(input: &str) -> IResult<&str, Vec<(String, u32, Vec<String>)>> {
+    separated_list0(
+        line_ending,
+        tuple((
+            preceded(tag("Valve "), map(alpha1, String::from)),
+            preceded(tag(" has flow rate="), map_res(digit1, str::parse)),
+            preceded(
+                alt((
+                    tag("; tunnel leads to valve "),
+                    tag("; tunnels lead to valves "),
+                )),
+                separated_list0(tag(", "), map(alpha1, String::from)),
+            ),
+        )),
+    )(input)
+}
+
+/// The valves by index, with the length of the shortest path between every pair of them.
+pub struct Network {
+    /// Index, flow rate and the indices of the adjacent valves.
+    valves: Vec<(u32, u32, Vec<u32>)>,
+    dist: Vec<Vec<u32>>,
+    /// The index of valve `AA`.
+    start: u32,
+}
+
+pub type Parsed = Network;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    let valves = parse_all(input, parse_input)?;
+    let label_to_idx: HashMap<String, u32> = HashMap::from_iter(
+        valves
+            .iter()
+            .enumerate()
+            .map(|(idx, (label, _, _))| (label.clone(), idx as u32)),
+    );
+    let find = |label: &str| {
+        label_to_idx
+            .get(label)
+            .copied()
+            .ok_or_else(|| SolveError::Invalid(format!("there is no valve {}", label)))
+    };
+    let valves = valves
+        .iter()
+        .enumerate()
+        .map(|(idx, (_, flow_rate, adj_labels))| {
+            let adjacencies = adj_labels
+                .iter()
+                .map(|adj_label| find(adj_label))
+                .collect::<Result<_, _>>()?;
+            Ok((idx as u32, *flow_rate, adjacencies))
+        })
+        .collect::<Result<Vec<(u32, u32, Vec<u32>)>, SolveError>>()?;
+    let start = find("AA")?;
+
+    // Find shortest path between all valve pairs
+    let mut dist = vec![vec![u32::MAX; valves.len()]; valves.len()];
+    for (valve_idx, _, adjacencies) in &valves {
+        for adj_valve_idx in adjacencies {
+            dist[*valve_idx as usize][*adj_valve_idx as usize] = 1;
+        }
+        dist[*valve_idx as usize][*valve_idx as usize] = 0;
+    }
+    for k in 0..valves.len() {
+        for i in 0..valves.len() {
+            for j in 0..valves.len() {
+                dist[i][j] = dist[i][j].min(dist[i][k].saturating_add(dist[k][j]));
+            }
+        }
+    }
+
+    Ok(Network {
+        start,
+        valves,
+        dist,
+    })
+}
+
+/// Where each of `AGENTS` agents is and when it gets there, the valves opened so far and the
+/// pressure they will release by the end.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+struct State<const AGENTS: usize> {
+    time: [u32; AGENTS],
+    valve_idx: [u32; AGENTS],
+    opened_valves: u64,
+    score: u32,
+}
+
+impl<const AGENTS: usize> State<AGENTS> {
+    fn open_valve(&mut self, idx: u32) {
+        self.opened_valves |= 1 << idx;
+    }
+
+    fn is_valve_opened(&self, idx: u32) -> bool {
+        (self.opened_valves & (1 << idx)) != 0
+    }
+}
+
+/// The most pressure `AGENTS` agents can release together in `max_time` minutes.
+struct Pressure<'a, const AGENTS: usize> {
+    network: &'a Network,
+    max_time: u32,
+}
+
+impl<const AGENTS: usize> Pressure<'_, AGENTS> {
+    /// The valves that are worth opening and still closed in `state`, with their flow rates.
+    fn closed_valves<'a>(
+        &'a self,
+        state: &'a State<AGENTS>,
+    ) -> impl Iterator<Item = (u32, u32)> + 'a {
+        self.network
+            .valves
+            .iter()
+            .filter(|&&(idx, flow_rate, _)| flow_rate > 0 && !state.is_valve_opened(idx))
+            .map(|&(idx, flow_rate, _)| (idx, flow_rate))
+    }
+
+    /// The time at which `agent` would have opened the valve `idx`, if it goes there next.
+    fn opened_at(&self, state: &State<AGENTS>, agent: usize, idx: u32) -> u32 {
+        let dist = self.network.dist[state.valve_idx[agent] as usize][idx as usize];
+        state.time[agent] + dist + 1
+    }
+}
+
+impl<const AGENTS: usize> Problem for Pressure<'_, AGENTS> {
+    type State = State<AGENTS>;
+    type Value = u32;
+    /// Where the agents are and when they get there, in any order, and the opened valves.
+    type Key = ([(u32, u32); AGENTS], u64);
+
+    const GOAL: Goal = Goal::Maximise;
+
+    fn branch(&self, state: &State<AGENTS>) -> Vec<State<AGENTS>> {
+        let mut adj_states = vec![];
+
+        for (adj_valve_idx, adj_flow_rate) in self.closed_valves(state) {
+            for agent in 0..AGENTS {
+                // Move to valve and open it
+                let time = self.opened_at(state, agent, adj_valve_idx);
+                if time >= self.max_time {
+                    continue;
+                }
+
+                let mut adj_state = *state;
+                adj_state.time[agent] = time;
+                adj_state.valve_idx[agent] = adj_valve_idx;
+                adj_state.open_valve(adj_valve_idx);
+                adj_state.score += adj_flow_rate * (self.max_time - time);
+                adj_states.push(adj_state);
+            }
+        }
+
+        adj_states
+    }
+
+    fn bound(&self, state: &State<AGENTS>) -> u32 {
+        // Every closed valve opened by whichever agent could get there first
+        state.score
+            + self
+                .closed_valves(state)
+                .map(|(idx, flow_rate)| {
+                    let time = (0..AGENTS)
+                        .map(|agent| self.opened_at(state, agent, idx))
+                        .min()
+                        .unwrap_or(self.max_time);
+                    flow_rate * self.max_time.saturating_sub(time)
+                })
+                .sum::<u32>()
+    }
+
+    fn value(&self, state: &State<AGENTS>) -> Option<u32> {
+        Some(state.score)
+    }
+
+    fn dominance(&self, state: &State<AGENTS>) -> Option<(Self::Key, u32)> {
+        // The agents are interchangeable, so states that only swap them are the same
+        let mut agents: [(u32, u32); AGENTS] =
+            std::array::from_fn(|agent| (state.time[agent], state.valve_idx[agent]));
+        agents.sort_unstable();
+        Some(((agents, state.opened_valves), state.score))
+    }
+}
+
+fn max_pressure<const AGENTS: usize>(network: &Network, max_time: u32) -> u32 {
+    let start_state = State {
+        time: [0; AGENTS],
+        valve_idx: [network.start; AGENTS],
+        opened_valves: 0,
+        score: 0,
+    };
+
+    let pressure = Pressure::<AGENTS> { network, max_time };
+    bnb::solve(&pressure, [start_state], Visited::Off).unwrap_or(0)
+}
+
+pub fn part_one(network: &Parsed) -> Result<u32, SolveError> {
+    Ok(max_pressure::<1>(network, 30))
+}
+
+pub fn part_two(network: &Parsed) -> Result<u32, SolveError> {
+    Ok(max_pressure::<2>(network, 26))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 16);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(1651));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 16);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(1707));
+    }
+
+    #[test]
+    fn test_unknown_valve() {
+        let input = "Valve AA has flow rate=0; tunnel leads to valve BB\n";
+        assert!(matches!(
+            parse(input),
+            Err(SolveError::Invalid(message)) if message == "there is no valve BB"
+        ));
+        let input = "Valve BB has flow rate=0; tunnel leads to valve BB\n";
+        assert!(matches!(
+            parse(input),
+            Err(SolveError::Invalid(message)) if message == "there is no valve AA"
+        ));
+    }
+}
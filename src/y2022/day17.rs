@@ -0,0 +1,225 @@
+use crate::error::{parse_all, SolveError};
+use crate::helpers::cycle;
+use nom::{branch::alt, character::complete::char, combinator::value, multi::many1, IResult};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Push {
+    Left,
+    Right,
+}
+
+const ROCK_SHAPES: [[u8; 4]; 5] = [
+    [0b01111000, 0b00000000, 0b00000000, 0b00000000],
+    [0b00100000, 0b01110000, 0b00100000, 0b00000000],
+    [0b01110000, 0b00010000, 0b00010000, 0b00000000],
+    [0b01000000, 0b01000000, 0b01000000, 0b01000000],
+    [0b01100000, 0b01100000, 0b00000000, 0b00000000],
+];
+
+/// A row with every column blocked, like the floor.
+const FULL_ROW: u8 = 0b01111111;
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Push>> {
+    many1(alt((
+        value(Push::Left, char('<')),
+        value(Push::Right, char('>')),
+    )))(input)
+}
+
+/// The pattern of jets, repeated forever.
+pub type Parsed = Vec<Push>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+/// The settled rocks, one bitmask per row from the floor up, and where the patterns of jets and
+/// rock shapes continue.
+struct Chamber<'a> {
+    jets: &'a [Push],
+    rows: Vec<u8>,
+    jet_idx: usize,
+    rock_idx: usize,
+}
+
+impl<'a> Chamber<'a> {
+    fn new(jets: &'a [Push]) -> Self {
+        Chamber {
+            jets,
+            rows: vec![],
+            jet_idx: 0,
+            rock_idx: 0,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// What decides how the next rocks fall: the position in both patterns and the rows that a
+    /// falling rock can still reach.
+    ///
+    /// Rocks only move sideways and down, so no part of a rock gets below the last row with an
+    /// empty cell that is connected to the open space above by such moves. The rows under it
+    /// never matter again, and the floor acts like a full row. Two chambers with the same
+    /// signature therefore grow the same way from then on, so a repeated signature is a cycle.
+    fn signature(&self) -> (usize, usize, Vec<u8>) {
+        let mut reachable = FULL_ROW;
+        let mut lowest = self.rows.len();
+        for (y, &row) in self.rows.iter().enumerate().rev() {
+            let free = !row & FULL_ROW;
+            reachable &= free;
+            loop {
+                let spread = (reachable | reachable << 1 | reachable >> 1) & free;
+                if spread == reachable {
+                    break;
+                }
+                reachable = spread;
+            }
+            if reachable == 0 {
+                break;
+            }
+            lowest = y;
+        }
+        (self.jet_idx, self.rock_idx, self.rows[lowest..].to_vec())
+    }
+
+    fn drop_rock(&mut self) {
+        let chamber = &mut self.rows;
+        let mut rock = ROCK_SHAPES[self.rock_idx];
+        self.rock_idx = (self.rock_idx + 1) % ROCK_SHAPES.len();
+        for row in &mut rock {
+            *row >>= 2;
+        }
+
+        let mut y = chamber.len() + 3;
+
+        loop {
+            let push = &self.jets[self.jet_idx];
+            self.jet_idx = (self.jet_idx + 1) % self.jets.len();
+            match push {
+                Push::Left => {
+                    let can_move_left = rock.iter().enumerate().all(|(dy, row)| {
+                        if (row & (1 << 6)) != 0 {
+                            false
+                        } else if y + dy < chamber.len() {
+                            chamber[y + dy] & (row << 1) == 0
+                        } else {
+                            true
+                        }
+                    });
+                    if can_move_left {
+                        for row in &mut rock {
+                            *row <<= 1;
+                        }
+                    }
+                }
+                Push::Right => {
+                    let can_move_right = rock.iter().enumerate().all(|(dy, row)| {
+                        if (row & 1) != 0 {
+                            false
+                        } else if y + dy < chamber.len() {
+                            chamber[y + dy] & (row >> 1) == 0
+                        } else {
+                            true
+                        }
+                    });
+                    if can_move_right {
+                        for row in &mut rock {
+                            *row >>= 1;
+                        }
+                    }
+                }
+            }
+
+            let can_move_down = y > 0
+                && rock.iter().enumerate().all(|(dy, row)| {
+                    if y + dy - 1 < chamber.len() {
+                        chamber[y + dy - 1] & row == 0
+                    } else {
+                        true
+                    }
+                });
+            if can_move_down {
+                y -= 1;
+            } else {
+                for (dy, &row) in rock.iter().enumerate() {
+                    if row == 0 {
+                        continue;
+                    }
+
+                    if y + dy >= chamber.len() {
+                        chamber.resize(y + dy + 1, 0);
+                    }
+
+                    chamber[y + dy] |= row;
+                }
+
+                break;
+            }
+        }
+    }
+}
+
+pub fn part_one(jets: &Parsed) -> Option<usize> {
+    let mut chamber = Chamber::new(jets);
+    for _step in 0..2022 {
+        chamber.drop_rock();
+    }
+
+    Some(chamber.height())
+}
+
+pub fn part_two(jets: &Parsed) -> Option<usize> {
+    let mut chamber = Chamber::new(jets);
+
+    let mut heights = vec![0];
+    let cycle = cycle::find(
+        &mut chamber,
+        |chamber| {
+            chamber.drop_rock();
+            heights.push(chamber.height());
+        },
+        Chamber::signature,
+    );
+
+    Some(cycle::extrapolate(cycle, &heights, 1000000000000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 17);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(3068));
+    }
+
+    #[test]
+    fn test_signature() {
+        let jets = parse("<>").unwrap();
+        let mut chamber = Chamber::new(&jets);
+        assert_eq!(chamber.signature(), (0, 0, vec![]));
+
+        // the rows under a full row are out of reach, like everything under the floor
+        chamber.rows = vec![0b0000001, FULL_ROW, 0b0011100, 0b0010000];
+        assert_eq!(chamber.signature(), (0, 0, vec![0b0011100, 0b0010000]));
+
+        // a gap lets rocks slide under an overhang and fall further
+        chamber.rows = vec![0b1000000, 0b1111110, 0b0000000, 0b1110111];
+        assert_eq!(chamber.signature().2, chamber.rows);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 17);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(1514285714288));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert!(parse("\n").is_err());
+        assert!(parse("<<x>\n").is_err());
+    }
+}
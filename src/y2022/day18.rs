@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+
+use crate::error::{parse_all, SolveError};
+use crate::helpers::point::Point3;
+use hashbrown::HashSet;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending},
+    combinator::{map, map_res},
+    multi::separated_list0,
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+pub type Coord = Point3<usize>;
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Coord>> {
+    separated_list0(
+        line_ending,
+        map(
+            tuple((
+                map_res(digit1, str::parse),
+                preceded(tag(","), map_res(digit1, str::parse)),
+                preceded(tag(","), map_res(digit1, str::parse)),
+            )),
+            Point3::from,
+        ),
+    )(input)
+}
+
+/// The position of every lava cube.
+pub type Parsed = Vec<Coord>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+fn adj_coords(coord: Coord, max: Coord) -> Vec<Coord> {
+    let mut adj_coords = vec![];
+
+    for (dx, dy, dz) in [(1, 0, 0), (0, 1, 0), (0, 0, 1)] {
+        let step = Point3::new(dx, dy, dz);
+        if coord.x >= dx && coord.y >= dy && coord.z >= dz {
+            adj_coords.push(coord - step);
+        }
+        let next = coord + step;
+        if next.x < max.x && next.y < max.y && next.z < max.z {
+            adj_coords.push(next);
+        }
+    }
+
+    adj_coords
+}
+
+/// The cubes, moved away from the origin by one so that there is air all around them, whether
+/// each cell is a cube, and the size of that grid.
+fn lava_grid(coords: &[Coord]) -> (Vec<Coord>, Vec<Vec<Vec<bool>>>, Coord) {
+    let coords: Vec<Coord> = coords
+        .iter()
+        .map(|&coord| coord + Point3::new(1, 1, 1))
+        .collect();
+
+    let max = coords.iter().fold(Coord::default(), |max, &coord| {
+        Point3::new(
+            max.x.max(coord.x + 2),
+            max.y.max(coord.y + 2),
+            max.z.max(coord.z + 2),
+        )
+    });
+
+    let mut grid = vec![vec![vec![false; max.z]; max.y]; max.x];
+    for coord in &coords {
+        grid[coord.x][coord.y][coord.z] = true;
+    }
+    (coords, grid, max)
+}
+
+pub fn part_one(coords: &Parsed) -> Result<u32, SolveError> {
+    let (coords, grid, max) = lava_grid(coords);
+
+    let mut surface_area = 0;
+
+    for &coord in &coords {
+        for adj in adj_coords(coord, max) {
+            if !grid[adj.x][adj.y][adj.z] {
+                surface_area += 1;
+            }
+        }
+    }
+
+    Ok(surface_area)
+}
+
+pub fn part_two(coords: &Parsed) -> Result<u32, SolveError> {
+    let (_, grid, max) = lava_grid(coords);
+
+    let mut visited = HashSet::<Coord>::new();
+    let mut queue = VecDeque::<Coord>::new();
+
+    queue.push_back(Point3::default());
+    visited.insert(Point3::default());
+
+    let mut surface_area = 0;
+
+    while let Some(coord) = queue.pop_front() {
+        for adj in adj_coords(coord, max) {
+            if grid[adj.x][adj.y][adj.z] {
+                surface_area += 1;
+            } else if visited.insert(adj) {
+                queue.push_back(adj);
+            }
+        }
+    }
+
+    Ok(surface_area)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 18);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(64));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 18);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(58));
+    }
+}
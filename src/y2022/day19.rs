@@ -0,0 +1,226 @@
+use crate::error::{parse_all, SolveError};
+use crate::helpers::bnb::{self, Goal, Problem, Visited};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending},
+    combinator::{map, map_res, opt},
+    multi::separated_list0,
+    sequence::{delimited, preceded, terminated, tuple},
+    IResult,
+};
+use num::Integer;
+
+pub type Costs = [u32; 3];
+pub type Blueprint = [Costs; 4];
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Blueprint>> {
+    separated_list0(line_ending, parse_blueprint)(input)
+}
+
+/// Every blueprint, in the order of their IDs.
+pub type Parsed = Vec<Blueprint>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+fn parse_blueprint(input: &str) -> IResult<&str, Blueprint> {
+    preceded(
+        delimited(tag("Blueprint "), digit1, tag(":")),
+        map(
+            tuple((
+                delimited(tag(" Each ore robot costs "), parse_costs, tag(".")),
+                delimited(tag(" Each clay robot costs "), parse_costs, tag(".")),
+                delimited(tag(" Each obsidian robot costs "), parse_costs, tag(".")),
+                delimited(tag(" Each geode robot costs "), parse_costs, tag(".")),
+            )),
+            |(a, b, c, d)| [a, b, c, d],
+        ),
+    )(input)
+}
+
+fn parse_costs(input: &str) -> IResult<&str, Costs> {
+    map(
+        tuple((
+            terminated(map_res(digit1, str::parse), tag(" ore")),
+            opt(delimited(
+                tag(" and "),
+                map_res(digit1, str::parse),
+                tag(" clay"),
+            )),
+            opt(delimited(
+                tag(" and "),
+                map_res(digit1, str::parse),
+                tag(" obsidian"),
+            )),
+        )),
+        |(a, b, c)| [a, b.unwrap_or(0), c.unwrap_or(0)],
+    )(input)
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct State {
+    time: u32,
+    robots: [u32; 4],
+    resources: [u32; 4],
+}
+
+impl State {
+    fn step_time(&mut self, time_steps: u32) {
+        self.time += time_steps;
+        for idx in 0..4 {
+            self.resources[idx] += self.robots[idx] * time_steps;
+        }
+    }
+
+    fn build_robot(&self, robot_idx: usize, costs: Costs) -> Option<Self> {
+        let mut t = 0;
+
+        for idx in (0..3).rev() {
+            if self.resources[idx] >= costs[idx] {
+                // Already have enough of this resource
+                continue;
+            }
+            if self.robots[idx] == 0 {
+                assert_eq!(self.resources[idx], 0);
+
+                // No robot that produces this resource
+                return None;
+            }
+            let diff_resources = costs[idx] - self.resources[idx];
+            t = t.max(Integer::div_ceil(&diff_resources, &self.robots[idx]));
+        }
+
+        let mut state = *self;
+
+        // Gather required resources
+        state.step_time(t);
+
+        // Build the robot
+        for (resource, cost) in state.resources.iter_mut().zip(costs) {
+            *resource -= cost;
+        }
+        assert!(
+            t == 0
+                || state.resources[0] < costs[0]
+                || state.resources[1] < costs[1]
+                || state.resources[2] < costs[2]
+        );
+        state.step_time(1);
+        state.robots[robot_idx] += 1;
+
+        Some(state)
+    }
+}
+
+/// The most geodes that can be opened with one blueprint in the given time.
+struct Geodes {
+    blueprint: Blueprint,
+    max_time: u32,
+}
+
+impl Problem for Geodes {
+    type State = State;
+    type Value = u32;
+    type Key = ();
+
+    const GOAL: Goal = Goal::Maximise;
+
+    fn branch(&self, &state: &State) -> Vec<State> {
+        if state.time == self.max_time {
+            return vec![];
+        }
+        let mut new_states = vec![];
+
+        // Build robots, prioritizing those that produce higher-tier resources (i.e. geodes)
+        new_states.extend((0..4).rev().filter_map(|robot_idx| {
+            state
+                .build_robot(robot_idx, self.blueprint[robot_idx])
+                .filter(|new_state| new_state.time <= self.max_time)
+        }));
+
+        // If no more robots can be built from this state, just run through to the end
+        if new_states.is_empty() {
+            let mut new_state = state;
+            new_state.step_time(self.max_time - state.time);
+            new_states.push(new_state);
+        }
+
+        new_states
+    }
+
+    fn bound(&self, state: &State) -> u32 {
+        let remaining_time = self.max_time - state.time;
+        let mut upper_bound = 0;
+
+        // Current geodes
+        upper_bound += state.resources[3];
+
+        // Geodes produced by current robots
+        upper_bound += remaining_time * state.robots[3];
+
+        // Geodes produced if we build a robot every timestep (triangular number)
+        if remaining_time > 1 {
+            upper_bound += (remaining_time - 1) * remaining_time / 2;
+        }
+
+        upper_bound
+    }
+
+    fn value(&self, state: &State) -> Option<u32> {
+        if state.time == self.max_time {
+            Some(state.resources[3])
+        } else {
+            None
+        }
+    }
+}
+
+fn eval_blueprint(blueprint: Blueprint, max_time: u32) -> u32 {
+    let start_state = State {
+        time: 0,
+        robots: [1, 0, 0, 0],
+        resources: [0, 0, 0, 0],
+    };
+
+    let geodes = Geodes {
+        blueprint,
+        max_time,
+    };
+    bnb::solve(&geodes, [start_state], Visited::Unbounded).unwrap_or(0)
+}
+
+pub fn part_one(blueprints: &Parsed) -> Result<u32, SolveError> {
+    Ok(blueprints
+        .iter()
+        .copied()
+        .enumerate()
+        .map(|(id, blueprint)| eval_blueprint(blueprint, 24) * (id as u32 + 1))
+        .sum())
+}
+
+pub fn part_two(blueprints: &Parsed) -> Result<u32, SolveError> {
+    Ok(blueprints
+        .iter()
+        .copied()
+        .take(3)
+        .map(|blueprint| eval_blueprint(blueprint, 32))
+        .product())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 19);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(33));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 19);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(3472));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::error::{parse_all, SolveError};
+use crate::helpers::parse_signed_int;
+use nom::{character::complete::line_ending, multi::separated_list0, IResult};
+
+#[derive(Debug)]
+struct Node {
+    value: i64,
+    prev_idx: usize,
+    next_idx: usize,
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<i64>> {
+    separated_list0(line_ending, parse_signed_int)(input)
+}
+
+/// The numbers of the encrypted file.
+pub type Parsed = Vec<i64>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+pub fn solve(file: &Parsed, decryption_key: i64, mix_count: usize) -> Option<i64> {
+    // mixing moves every number around the others, which takes at least two numbers.
+    if file.len() < 2 {
+        return None;
+    }
+
+    let len = file.len();
+    let mut nodes = file
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| Node {
+            value: value * decryption_key,
+            prev_idx: (idx + len - 1) % len,
+            next_idx: (idx + 1) % len,
+        })
+        .collect::<Vec<_>>();
+
+    for _ in 0..mix_count {
+        for idx in 0..len {
+            let Node {
+                value,
+                prev_idx,
+                next_idx,
+            } = nodes[idx];
+
+            // Remove node from list
+            nodes[prev_idx].next_idx = next_idx;
+            nodes[next_idx].prev_idx = prev_idx;
+
+            // Find new position
+            let mut prev_idx = prev_idx;
+            let mut next_idx = next_idx;
+            for _ in 0..(value.unsigned_abs() as usize % (len - 1)) {
+                if value > 0 {
+                    prev_idx = next_idx;
+                    next_idx = nodes[next_idx].next_idx;
+                } else {
+                    next_idx = prev_idx;
+                    prev_idx = nodes[prev_idx].prev_idx;
+                };
+            }
+
+            // Insert node into new position
+            nodes[prev_idx].next_idx = idx;
+            nodes[next_idx].prev_idx = idx;
+            nodes[idx].prev_idx = prev_idx;
+            nodes[idx].next_idx = next_idx;
+        }
+    }
+
+    let zero_idx = nodes.iter().position(|node| node.value == 0)?;
+    let mut mixed_file = Vec::with_capacity(len);
+
+    let mut cur_idx = zero_idx;
+    loop {
+        mixed_file.push(nodes[cur_idx].value);
+        cur_idx = nodes[cur_idx].next_idx;
+        if cur_idx == zero_idx {
+            break;
+        }
+    }
+
+    Some(mixed_file[1000 % len] + mixed_file[2000 % len] + mixed_file[3000 % len])
+}
+
+pub fn part_one(file: &Parsed) -> Option<i64> {
+    solve(file, 1, 1)
+}
+
+pub fn part_two(file: &Parsed) -> Option<i64> {
+    solve(file, 811589153, 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 20);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 20);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(1623178306));
+    }
+}
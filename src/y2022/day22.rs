@@ -0,0 +1,415 @@
+use crate::error::{parse_all, SolveError};
+use crate::helpers::grid::Grid;
+use crate::helpers::point::{Direction, Point2};
+use hashbrown::HashMap;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending},
+    combinator::{map, map_res, value},
+    multi::{count, many0_count, many1, separated_list0},
+    sequence::{pair, separated_pair},
+    IResult,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Tile {
+    Open,
+    Wall,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum PathInstruction {
+    Move(usize),
+    TurnLeft,
+    TurnRight,
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Vec<(usize, Vec<Tile>)>, Vec<PathInstruction>)> {
+    separated_pair(
+        parse_board_rows,
+        count(line_ending, 2),
+        parse_path_instructions,
+    )(input)
+}
+
+/// The board, cut into square regions that become the faces of the cube in part two.
+pub struct Board {
+    region_size: usize,
+    start_region_x: usize,
+    regions: HashMap<(usize, usize), Grid<Tile>>,
+    path_instructions: Vec<PathInstruction>,
+}
+
+pub type Parsed = Board;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    let (board_rows, path_instructions) = parse_all(input, parse_input)?;
+    let region_size = region_size(&board_rows);
+
+    let start_region_x = board_rows[0].0 / region_size;
+
+    let mut region_rows: HashMap<(usize, usize), Vec<Vec<Tile>>> = HashMap::new();
+    board_rows
+        .into_iter()
+        .enumerate()
+        .for_each(|(y, (x, mut tiles))| {
+            let region_y = y / region_size;
+            let mut region_x = x / region_size;
+
+            while !tiles.is_empty() {
+                let remaining_tiles = tiles.split_off(region_size);
+
+                let region = region_rows.entry((region_y, region_x)).or_default();
+                region.push(tiles);
+
+                tiles = remaining_tiles;
+                region_x += 1;
+            }
+        });
+
+    let regions = region_rows
+        .into_iter()
+        .map(|(region, rows)| (region, Grid::from_rows(rows)))
+        .collect();
+
+    Ok(Board {
+        region_size,
+        start_region_x,
+        regions,
+        path_instructions,
+    })
+}
+
+/// The side length of the cube's faces, derived from the area of the board.
+fn region_size(board_rows: &[(usize, Vec<Tile>)]) -> usize {
+    let tiles: usize = board_rows.iter().map(|(_, tiles)| tiles.len()).sum();
+    (1..).find(|size| size * size * 6 >= tiles).unwrap()
+}
+
+fn parse_board_rows(input: &str) -> IResult<&str, Vec<(usize, Vec<Tile>)>> {
+    separated_list0(
+        line_ending,
+        pair(
+            many0_count(tag(" ")),
+            many1(alt((
+                value(Tile::Open, tag(".")),
+                value(Tile::Wall, tag("#")),
+            ))),
+        ),
+    )(input)
+}
+
+fn parse_path_instructions(input: &str) -> IResult<&str, Vec<PathInstruction>> {
+    many1(alt((
+        value(PathInstruction::TurnLeft, tag("L")),
+        value(PathInstruction::TurnRight, tag("R")),
+        map(map_res(digit1, str::parse), PathInstruction::Move),
+    )))(input)
+}
+
+#[derive(Clone, Copy)]
+struct State {
+    region_x: usize,
+    region_y: usize,
+    local_x: usize,
+    local_y: usize,
+    facing: Direction,
+}
+
+impl State {
+    fn password(self, region_size: usize) -> usize {
+        let x = self.region_x * region_size + self.local_x;
+        let y = self.region_y * region_size + self.local_y;
+        let facing = match self.facing {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+        1000 * (y + 1) + 4 * (x + 1) + facing
+    }
+
+    fn turn_left(self) -> Self {
+        Self {
+            facing: self.facing.turn_left(),
+            ..self
+        }
+    }
+
+    fn turn_right(self) -> Self {
+        Self {
+            facing: self.facing.turn_right(),
+            ..self
+        }
+    }
+
+    fn turn_region_towards(mut self, facing: Direction, region_size: usize) -> Self {
+        while self.facing != facing {
+            self.facing = self.facing.turn_left();
+            (self.local_x, self.local_y) = (self.local_y, region_size - self.local_x - 1);
+        }
+        self
+    }
+
+    fn move_forward(
+        mut self,
+        region_size: usize,
+        adjacencies: &HashMap<(usize, usize, Direction), (usize, usize, Direction)>,
+    ) -> Self {
+        match self.facing {
+            Direction::Right if self.local_x + 1 < region_size => {
+                self.local_x += 1;
+                return self;
+            }
+            Direction::Right => self.local_x = 0,
+            Direction::Down if self.local_y + 1 < region_size => {
+                self.local_y += 1;
+                return self;
+            }
+            Direction::Down => self.local_y = 0,
+            Direction::Left if self.local_x > 0 => {
+                self.local_x -= 1;
+                return self;
+            }
+            Direction::Left => self.local_x = region_size - 1,
+            Direction::Up if self.local_y > 0 => {
+                self.local_y -= 1;
+                return self;
+            }
+            Direction::Up => self.local_y = region_size - 1,
+        }
+
+        let (next_region_y, next_region_x, next_facing) =
+            adjacencies[&(self.region_y, self.region_x, self.facing)];
+        self.region_x = next_region_x;
+        self.region_y = next_region_y;
+        self.turn_region_towards(next_facing, region_size)
+    }
+}
+
+fn solve(
+    board: &Board,
+    region_adjacencies: HashMap<(usize, usize, Direction), (usize, usize, Direction)>,
+) -> usize {
+    let region_size = board.region_size;
+
+    let mut state = State {
+        region_x: board.start_region_x,
+        region_y: 0,
+        local_x: 0,
+        local_y: 0,
+        facing: Direction::Right,
+    };
+
+    for &instruction in &board.path_instructions {
+        match instruction {
+            PathInstruction::Move(steps) => {
+                for _ in 0..steps {
+                    let next_state = state.move_forward(region_size, &region_adjacencies);
+                    let region = &board.regions[&(next_state.region_y, next_state.region_x)];
+                    if matches!(
+                        region[Point2::new(next_state.local_x, next_state.local_y)],
+                        Tile::Wall
+                    ) {
+                        break;
+                    }
+                    state = next_state;
+                }
+            }
+            PathInstruction::TurnLeft => {
+                state = state.turn_left();
+            }
+            PathInstruction::TurnRight => {
+                state = state.turn_right();
+            }
+        }
+    }
+
+    state.password(region_size)
+}
+
+pub fn part_one(board: &Parsed) -> Result<usize, SolveError> {
+    use Direction::*;
+    let region_size = board.region_size;
+
+    // TODO: find a non-hardcoded way of computing these adjacencies
+    let region_adjacencies: HashMap<(usize, usize, Direction), (usize, usize, Direction)> =
+        if region_size == 4 {
+            // ..1.
+            // 234.
+            // ..56
+            HashMap::from([
+                // 1
+                ((0, 2, Right), (0, 2, Right)),
+                ((0, 2, Down), (1, 2, Down)),
+                ((0, 2, Left), (0, 2, Left)),
+                ((0, 2, Up), (2, 2, Up)),
+                // 2
+                ((1, 0, Right), (1, 1, Right)),
+                ((1, 0, Down), (1, 0, Down)),
+                ((1, 0, Left), (1, 2, Left)),
+                ((1, 0, Up), (1, 0, Up)),
+                // 3
+                ((1, 1, Right), (1, 2, Right)),
+                ((1, 1, Down), (1, 1, Down)),
+                ((1, 1, Left), (1, 0, Left)),
+                ((1, 1, Up), (1, 1, Up)),
+                // 4
+                ((1, 2, Right), (1, 0, Right)),
+                ((1, 2, Down), (2, 2, Down)),
+                ((1, 2, Left), (1, 1, Left)),
+                ((1, 2, Up), (0, 2, Up)),
+                // 5
+                ((2, 2, Right), (2, 3, Right)),
+                ((2, 2, Down), (0, 2, Down)),
+                ((2, 2, Left), (2, 3, Left)),
+                ((2, 2, Up), (1, 2, Up)),
+                // 6
+                ((2, 3, Right), (2, 2, Right)),
+                ((2, 3, Down), (2, 3, Down)),
+                ((2, 3, Left), (2, 2, Left)),
+                ((2, 3, Up), (2, 3, Up)),
+            ])
+        } else {
+            // .12
+            // .3.
+            // 45.
+            // 6..
+            HashMap::from([
+                // 1
+                ((0, 1, Right), (0, 2, Right)),
+                ((0, 1, Down), (1, 1, Down)),
+                ((0, 1, Left), (0, 2, Left)),
+                ((0, 1, Up), (2, 1, Up)),
+                // 2
+                ((0, 2, Right), (0, 1, Right)),
+                ((0, 2, Down), (0, 2, Down)),
+                ((0, 2, Left), (0, 1, Left)),
+                ((0, 2, Up), (0, 2, Up)),
+                // 3
+                ((1, 1, Right), (1, 1, Right)),
+                ((1, 1, Down), (2, 1, Down)),
+                ((1, 1, Left), (1, 1, Left)),
+                ((1, 1, Up), (0, 1, Up)),
+                // 4
+                ((2, 0, Right), (2, 1, Right)),
+                ((2, 0, Down), (3, 0, Down)),
+                ((2, 0, Left), (2, 1, Left)),
+                ((2, 0, Up), (3, 0, Up)),
+                // 5
+                ((2, 1, Right), (2, 0, Right)),
+                ((2, 1, Down), (0, 1, Down)),
+                ((2, 1, Left), (2, 0, Left)),
+                ((2, 1, Up), (1, 1, Up)),
+                // 6
+                ((3, 0, Right), (3, 0, Right)),
+                ((3, 0, Down), (2, 0, Down)),
+                ((3, 0, Left), (3, 0, Left)),
+                ((3, 0, Up), (2, 0, Up)),
+            ])
+        };
+
+    Ok(solve(board, region_adjacencies))
+}
+
+pub fn part_two(board: &Parsed) -> Result<usize, SolveError> {
+    use Direction::*;
+    let region_size = board.region_size;
+
+    // TODO: find a non-hardcoded way of computing these adjacencies
+    let region_adjacencies: HashMap<(usize, usize, Direction), (usize, usize, Direction)> =
+        if region_size == 4 {
+            // ..1.
+            // 234.
+            // ..56
+            HashMap::from([
+                // 1
+                ((0, 2, Right), (2, 3, Left)),
+                ((0, 2, Down), (1, 2, Down)),
+                ((0, 2, Left), (1, 1, Down)),
+                ((0, 2, Up), (1, 0, Down)),
+                // 2
+                ((1, 0, Right), (1, 1, Right)),
+                ((1, 0, Down), (2, 2, Up)),
+                ((1, 0, Left), (2, 3, Up)),
+                ((1, 0, Up), (1, 0, Down)),
+                // 3
+                ((1, 1, Right), (1, 2, Right)),
+                ((1, 1, Up), (0, 2, Right)),
+                ((1, 1, Left), (1, 0, Left)),
+                ((1, 1, Up), (0, 2, Right)),
+                // 4
+                ((1, 2, Right), (2, 3, Down)),
+                ((1, 2, Down), (2, 2, Down)),
+                ((1, 2, Left), (1, 1, Left)),
+                ((1, 2, Up), (0, 2, Up)),
+                // 5
+                ((2, 2, Right), (2, 3, Right)),
+                ((2, 2, Down), (1, 0, Up)),
+                ((2, 2, Left), (1, 1, Up)),
+                ((2, 2, Up), (1, 2, Up)),
+                // 6
+                ((2, 3, Right), (0, 2, Left)),
+                ((2, 3, Down), (1, 0, Right)),
+                ((2, 3, Left), (2, 2, Left)),
+                ((2, 3, Up), (1, 2, Left)),
+            ])
+        } else {
+            // .12
+            // .3.
+            // 45.
+            // 6..
+            HashMap::from([
+                // 1
+                ((0, 1, Right), (0, 2, Right)),
+                ((0, 1, Down), (1, 1, Down)),
+                ((0, 1, Left), (2, 0, Right)),
+                ((0, 1, Up), (3, 0, Right)),
+                // 2
+                ((0, 2, Right), (2, 1, Left)),
+                ((0, 2, Down), (1, 1, Left)),
+                ((0, 2, Left), (0, 1, Left)),
+                ((0, 2, Up), (3, 0, Up)),
+                // 3
+                ((1, 1, Right), (0, 2, Up)),
+                ((1, 1, Down), (2, 1, Down)),
+                ((1, 1, Left), (2, 0, Down)),
+                ((1, 1, Up), (0, 1, Up)),
+                // 4
+                ((2, 0, Right), (2, 1, Right)),
+                ((2, 0, Down), (3, 0, Down)),
+                ((2, 0, Left), (0, 1, Right)),
+                ((2, 0, Up), (1, 1, Right)),
+                // 5
+                ((2, 1, Right), (0, 2, Left)),
+                ((2, 1, Down), (3, 0, Left)),
+                ((2, 1, Left), (2, 0, Left)),
+                ((2, 1, Up), (1, 1, Up)),
+                // 6
+                ((3, 0, Right), (2, 1, Up)),
+                ((3, 0, Down), (0, 2, Down)),
+                ((3, 0, Left), (0, 1, Down)),
+                ((3, 0, Up), (2, 0, Up)),
+            ])
+        };
+
+    Ok(solve(board, region_adjacencies))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 22);
+        assert_eq!(part_one(&parse(&input).unwrap()), Ok(6032));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 22);
+        assert_eq!(part_two(&parse(&input).unwrap()), Ok(5031));
+    }
+}
@@ -0,0 +1,165 @@
+use auto_enums::auto_enum;
+use hashbrown::{HashMap, HashSet};
+
+use crate::error::SolveError;
+use crate::helpers::grid::Grid;
+use crate::helpers::point::{Direction, Point2};
+
+type Pos = Point2<i32>;
+
+/// The smallest and the largest coordinates of any elf.
+fn get_bounds(positions: &HashSet<Pos>) -> (Pos, Pos) {
+    positions.iter().fold(
+        (
+            Point2::new(i32::MAX, i32::MAX),
+            Point2::new(i32::MIN, i32::MIN),
+        ),
+        |(min, max), pos| {
+            (
+                Point2::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Point2::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        },
+    )
+}
+
+/// One bit per neighbour of `pos` that is taken, row by row from the top left.
+fn get_pattern(pos: Pos, positions: &HashSet<Pos>) -> u8 {
+    let mut pattern = 0;
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            if (dx, dy) != (0, 0) {
+                let taken = positions.contains(&(pos + Point2::new(dx, dy)));
+                pattern = (pattern << 1) + taken as u8;
+            }
+        }
+    }
+    pattern
+}
+
+#[allow(dead_code)]
+fn print_positions(positions: &HashSet<Pos>) {
+    let (min, max) = get_bounds(positions);
+
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            if positions.contains(&Point2::new(x, y)) {
+                print!("#");
+            } else {
+                print!(".");
+            }
+        }
+        println!();
+    }
+    println!();
+}
+
+/// The positions of all elves.
+pub type Parsed = HashSet<Pos>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    let grove = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    Ok(grove
+        .iter()
+        .filter(|&(_, &elf)| elf)
+        .map(|(pos, _)| Point2::new(pos.x as i32, pos.y as i32))
+        .collect())
+}
+
+fn solve(positions: &Parsed) -> (Option<usize>, Option<usize>) {
+    let mut positions = positions.clone();
+
+    let mut rules: [(u8, Direction); 4] = [
+        // 1's mark positions that should be empty, 0's mark ignored positions
+        (0b11100000, Direction::Up),
+        (0b00000111, Direction::Down),
+        (0b10010100, Direction::Left),
+        (0b00101001, Direction::Right),
+    ];
+
+    let mut result_one = None;
+    let mut result_two = None;
+
+    for round in 0.. {
+        if round == 10 {
+            let num_elves = positions.len();
+            let (min, max) = get_bounds(&positions);
+            let num_tiles = (max.y - min.y + 1) * (max.x - min.x + 1);
+            result_one = Some(num_tiles as usize - num_elves)
+        }
+
+        let mut proposed_positions = HashMap::<Pos, Vec<Pos>>::new();
+
+        for &pos in &positions {
+            let pattern = get_pattern(pos, &positions);
+
+            let new_pos = if pattern == 0 {
+                pos
+            } else {
+                rules
+                    .iter()
+                    .find_map(|&(mask, direction)| {
+                        if pattern & mask == 0 {
+                            Some(pos + direction.vector())
+                        } else {
+                            None
+                        }
+                    })
+                    .unwrap_or(pos)
+            };
+
+            proposed_positions.entry(new_pos).or_default().push(pos);
+        }
+
+        #[auto_enum(Iterator)]
+        fn flat_map_cb((new_pos, old_positions): (Pos, Vec<Pos>)) -> impl Iterator<Item = Pos> {
+            if old_positions.len() == 1 {
+                std::iter::once(new_pos)
+            } else {
+                old_positions.into_iter()
+            }
+        }
+
+        let next_positions =
+            HashSet::from_iter(proposed_positions.into_iter().flat_map(flat_map_cb));
+
+        if positions.is_subset(&next_positions) {
+            result_two = Some(round + 1);
+            break;
+        }
+
+        positions = next_positions;
+        rules.rotate_left(1);
+    }
+
+    (result_one, result_two)
+}
+
+pub fn part_one(positions: &Parsed) -> Option<usize> {
+    solve(positions).0
+}
+
+pub fn part_two(positions: &Parsed) -> Option<usize> {
+    solve(positions).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 23);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(110));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 23);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(20));
+    }
+}
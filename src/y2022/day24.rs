@@ -0,0 +1,207 @@
+use std::ops::{Add, Rem, Sub};
+
+use crate::error::SolveError;
+use crate::helpers::grid::Grid;
+use crate::helpers::point::{Direction, Point2};
+use crate::helpers::search;
+
+trait ModAdd<Rhs = Self, Mod = Self> {
+    type Output;
+
+    fn mod_add(self, rhs: Rhs, modulus: Mod) -> Self::Output;
+}
+
+impl<T> ModAdd for T
+where
+    T: Add<Output = T> + Rem<Output = T>,
+{
+    type Output = T;
+
+    fn mod_add(self, rhs: Self, modulus: Self) -> Self::Output {
+        (self + rhs) % modulus
+    }
+}
+
+trait ModSub<Rhs = Self, Mod = Self> {
+    type Output;
+
+    fn mod_sub(self, rhs: Rhs, modulus: Mod) -> Self::Output;
+}
+
+impl<T> ModSub for T
+where
+    T: Add<Output = T> + Sub<Output = T> + Rem<Output = T> + Copy,
+{
+    type Output = T;
+
+    fn mod_sub(self, rhs: Self, modulus: Self) -> Self::Output {
+        (self + (modulus - rhs % modulus)) % modulus
+    }
+}
+
+type Coord = Point2<usize>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+enum Position {
+    TopLeft,
+    Valley(Coord),
+    BottomRight,
+}
+
+/// The blizzards by where they start, inside the walls of the valley.
+pub struct BlizzardGrid {
+    blizzards: Grid<Option<Direction>>,
+}
+
+impl BlizzardGrid {
+    fn is_cell_empty(&self, Point2 { x, y }: Coord, time: usize) -> bool {
+        let (width, height) = (self.blizzards.width(), self.blizzards.height());
+        self.blizzards[Point2::new(x, y.mod_sub(time, height))] != Some(Direction::Down)
+            && self.blizzards[Point2::new(x, y.mod_add(time, height))] != Some(Direction::Up)
+            && self.blizzards[Point2::new(x.mod_sub(time, width), y)] != Some(Direction::Right)
+            && self.blizzards[Point2::new(x.mod_add(time, width), y)] != Some(Direction::Left)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct State {
+    time: usize,
+    pos: Position,
+}
+
+pub type Parsed = BlizzardGrid;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    // the walls only leave room for the entrance and the exit, which are not part of the valley
+    let lines: Vec<&str> = input.lines().collect();
+    let valley = lines
+        .get(1..lines.len().saturating_sub(1))
+        .unwrap_or_default()
+        .iter()
+        .map(|line| line.trim_matches('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let blizzards = Grid::parse(&valley, |c| match c {
+        '.' => Some(None),
+        c => Direction::from_char(c).map(Some),
+    })?;
+    if blizzards.width() == 0 || blizzards.height() == 0 {
+        return Err(SolveError::Invalid("the valley is empty".to_string()));
+    }
+    Ok(BlizzardGrid { blizzards })
+}
+
+fn shortest_path(grid: &BlizzardGrid, start_state: State, goal_pos: Position) -> Option<usize> {
+    let max = Point2::new(grid.blizzards.width() - 1, grid.blizzards.height() - 1);
+
+    let get_valid_neighbors = |pos: Position| match pos {
+        Position::TopLeft => vec![Position::Valley(Point2::new(0, 0)), Position::TopLeft],
+        Position::BottomRight => vec![Position::Valley(max), Position::BottomRight],
+        Position::Valley(coord) => {
+            let mut neighbors = vec![];
+
+            if coord == max {
+                neighbors.push(Position::BottomRight);
+            }
+            if coord == Point2::new(0, 0) {
+                neighbors.push(Position::TopLeft)
+            }
+
+            neighbors.extend(grid.blizzards.neighbors4(coord).map(Position::Valley));
+
+            neighbors.push(Position::Valley(coord));
+
+            neighbors
+        }
+    };
+
+    let entrance_dist = max.x + max.y + 2;
+    let goal_dist = |pos: Position| {
+        let dist = match pos {
+            Position::BottomRight => 0,
+            Position::Valley(coord) => coord.manhattan(max) + 1,
+            Position::TopLeft => entrance_dist,
+        };
+
+        match goal_pos {
+            Position::TopLeft => entrance_dist - dist,
+            Position::BottomRight => dist,
+            Position::Valley(_) => unreachable!(),
+        }
+    };
+
+    let successors = |state: &State| {
+        let time = state.time + 1;
+        get_valid_neighbors(state.pos)
+            .into_iter()
+            .filter(move |&pos| match pos {
+                Position::TopLeft | Position::BottomRight => true,
+                Position::Valley(coord) => grid.is_cell_empty(coord, time),
+            })
+            .map(move |pos| (State { time, pos }, 1))
+    };
+
+    let found = search::astar(
+        [start_state],
+        successors,
+        |state| goal_dist(state.pos),
+        |state| state.pos == goal_pos,
+    )?;
+    Some(found.goal().time)
+}
+
+pub fn part_one(grid: &Parsed) -> Option<usize> {
+    shortest_path(
+        grid,
+        State {
+            time: 0,
+            pos: Position::TopLeft,
+        },
+        Position::BottomRight,
+    )
+}
+
+pub fn part_two(grid: &Parsed) -> Option<usize> {
+    let time = shortest_path(
+        grid,
+        State {
+            time: 0,
+            pos: Position::TopLeft,
+        },
+        Position::BottomRight,
+    )?;
+    let time = shortest_path(
+        grid,
+        State {
+            time,
+            pos: Position::BottomRight,
+        },
+        Position::TopLeft,
+    )?;
+    shortest_path(
+        grid,
+        State {
+            time,
+            pos: Position::TopLeft,
+        },
+        Position::BottomRight,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(2022, "examples", 24);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(18));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(2022, "examples", 24);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(54));
+    }
+}
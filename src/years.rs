@@ -0,0 +1,35 @@
+use crate::solution::Solution;
+
+/// The solved days of one year of puzzles.
+pub struct Year {
+    pub year: u16,
+    pub solutions: &'static [&'static dyn Solution],
+}
+
+/// Every year with solutions. `cargo scaffold` adds new years here.
+pub static YEARS: &[Year] = &[Year {
+    year: 2022,
+    solutions: crate::y2022::SOLUTIONS,
+}];
+
+pub fn find(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|entry| entry.year == year)
+}
+
+/// The year used when `--year` is not given: the latest one with solutions.
+pub fn latest() -> u16 {
+    YEARS
+        .iter()
+        .map(|entry| entry.year)
+        .max()
+        .expect("no years registered")
+}
+
+/// Looks up a single day, e.g. for `cargo solve`.
+pub fn find_day(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    find(year)?
+        .solutions
+        .iter()
+        .find(|solution| solution.day() == day)
+        .copied()
+}
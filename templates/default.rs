@@ -0,0 +1,26 @@
+use crate::error::SolveError;
+
+pub fn part_one(_input: &str) -> Result<u32, SolveError> {
+    Err(SolveError::Unsolved)
+}
+
+pub fn part_two(_input: &str) -> Result<u32, SolveError> {
+    Err(SolveError::Unsolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_one(&input), Err(SolveError::Unsolved));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_two(&input), Err(SolveError::Unsolved));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::error::SolveError;
+use crate::helpers::grid::{Grid, Pos};
+use crate::helpers::point::Point2;
+use crate::helpers::search;
+
+/// The cost of entering each cell, indexed as `grid[Point2::new(x, y)]`.
+pub type Parsed = Grid<u32>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    Grid::parse(input, |c| c.to_digit(10))
+}
+
+/// The cost of the cheapest path from `start` to `goal`, if there is one.
+fn shortest_path(grid: &Parsed, start: Pos, goal: Pos) -> Option<u32> {
+    let found = search::dijkstra(
+        [start],
+        |&point| grid.neighbors4(point).map(|next| (next, grid[next])),
+        |&point| point == goal,
+    )?;
+    Some(found.cost())
+}
+
+pub fn part_one(grid: &Parsed) -> Result<u32, SolveError> {
+    // from the top left to the bottom right corner.
+    let goal = Point2::new(
+        grid.width().saturating_sub(1),
+        grid.height().saturating_sub(1),
+    );
+    let _cost = shortest_path(grid, Point2::new(0, 0), goal);
+    Err(SolveError::Unsolved)
+}
+
+pub fn part_two(_grid: &Parsed) -> Result<u32, SolveError> {
+    Err(SolveError::Unsolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shortest_path() {
+        let grid = parse("191\n111\n").unwrap();
+        let start = Point2::new(0, 0);
+        assert_eq!(shortest_path(&grid, start, Point2::new(2, 0)), Some(4));
+        assert_eq!(shortest_path(&grid, start, Point2::new(3, 0)), None);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_one(&parse(&input).unwrap()), Err(SolveError::Unsolved));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_two(&parse(&input).unwrap()), Err(SolveError::Unsolved));
+    }
+}
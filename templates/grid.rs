@@ -0,0 +1,47 @@
+use crate::error::SolveError;
+use crate::helpers::grid::Grid;
+
+/// The cells of the puzzle input, indexed as `grid[Point2::new(x, y)]`.
+pub type Parsed = Grid<u8>;
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    Grid::parse(input, |c| c.is_ascii().then_some(c as u8))
+}
+
+pub fn part_one(_grid: &Parsed) -> Result<u32, SolveError> {
+    Err(SolveError::Unsolved)
+}
+
+pub fn part_two(_grid: &Parsed) -> Result<u32, SolveError> {
+    Err(SolveError::Unsolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::point::Point2;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = parse("ab\ncd\n").unwrap();
+        assert_eq!(grid[Point2::new(1, 0)], b'b');
+        assert_eq!(
+            grid.neighbors4(Point2::new(0, 0)).collect::<Vec<_>>(),
+            [Point2::new(1, 0), Point2::new(0, 1)]
+        );
+        assert!(parse("ab\nc\n").is_err());
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_one(&parse(&input).unwrap()), Err(SolveError::Unsolved));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_two(&parse(&input).unwrap()), Err(SolveError::Unsolved));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::error::{parse_all, SolveError};
+use nom::{
+    character::complete::{digit1, line_ending},
+    combinator::map_res,
+    multi::separated_list0,
+    IResult,
+};
+
+/// One line of the puzzle input.
+type Line = u32;
+
+/// Every line of the puzzle input.
+pub type Parsed = Vec<Line>;
+
+fn parse_line(input: &str) -> IResult<&str, Line> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Parsed> {
+    separated_list0(line_ending, parse_line)(input)
+}
+
+pub fn parse(input: &str) -> Result<Parsed, SolveError> {
+    parse_all(input, parse_input)
+}
+
+pub fn part_one(_lines: &Parsed) -> Result<u32, SolveError> {
+    Err(SolveError::Unsolved)
+}
+
+pub fn part_two(_lines: &Parsed) -> Result<u32, SolveError> {
+    Err(SolveError::Unsolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line("42"), Ok(("", 42)));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_one(&parse(&input).unwrap()), Err(SolveError::Unsolved));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_two(&parse(&input).unwrap()), Err(SolveError::Unsolved));
+    }
+}
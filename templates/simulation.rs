@@ -0,0 +1,78 @@
+use crate::error::SolveError;
+
+/// Everything that changes from one round to the next.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct State {
+    round: usize,
+    cells: Vec<Vec<u8>>,
+}
+
+impl State {
+    fn parse(input: &str) -> Result<Self, SolveError> {
+        Ok(State {
+            round: 0,
+            cells: input.lines().map(|line| line.bytes().collect()).collect(),
+        })
+    }
+
+    /// Advances the simulation by one round. Returns whether anything changed.
+    fn step(&mut self) -> bool {
+        self.round += 1;
+        true
+    }
+
+    /// Steps until nothing changes anymore or `max_rounds` have passed.
+    fn run(&mut self, max_rounds: usize) {
+        while self.round < max_rounds && self.step() {}
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32, SolveError> {
+    let mut state = State::parse(input)?;
+    state.run(10);
+    Err(SolveError::Unsolved)
+}
+
+pub fn part_two(input: &str) -> Result<u32, SolveError> {
+    let _state = State::parse(input)?;
+    Err(SolveError::Unsolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::cycle;
+
+    #[test]
+    fn test_run() {
+        let mut state = State::parse("#.\n.#\n").unwrap();
+        state.run(10);
+        assert_eq!(state.round, 10);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut state = State::parse("#.\n.#\n").unwrap();
+        let found = cycle::find(
+            &mut state,
+            |state| {
+                state.step();
+            },
+            // the round only counts up, so the cells alone decide the following rounds.
+            |state| state.cells.clone(),
+        );
+        assert_eq!(found, cycle::Cycle { start: 0, len: 1 });
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_one(&input), Err(SolveError::Unsolved));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file(YEAR, "examples", DAY);
+        assert_eq!(part_two(&input), Err(SolveError::Unsolved));
+    }
+}